@@ -3,9 +3,24 @@ use crate::tree::command_node::CommandNode;
 pub trait AmbiguityConsumer<S> {
     fn ambiguous(
         &mut self,
-        parent: dyn CommandNode<S>,
-        child: dyn CommandNode<S>,
-        sibling: dyn CommandNode<S>,
-        inputs: Vec<String>,
+        parent: &dyn CommandNode<S>,
+        child: &dyn CommandNode<S>,
+        sibling: &dyn CommandNode<S>,
+        inputs: &[String],
     );
 }
+
+impl<S, F> AmbiguityConsumer<S> for F
+where
+    F: FnMut(&dyn CommandNode<S>, &dyn CommandNode<S>, &dyn CommandNode<S>, &[String]),
+{
+    fn ambiguous(
+        &mut self,
+        parent: &dyn CommandNode<S>,
+        child: &dyn CommandNode<S>,
+        sibling: &dyn CommandNode<S>,
+        inputs: &[String],
+    ) {
+        self(parent, child, sibling, inputs)
+    }
+}
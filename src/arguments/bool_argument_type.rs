@@ -1,12 +1,13 @@
 use std::fmt::{Display, Formatter};
 
 use crate::{
-    context::command_context::CommandContext, error::BrigadierError,
+    context::command_context::CommandContext, exceptions::CommandSyntaxException,
     suggestion::suggestions_builder::SuggestionsBuilder,
 };
 
 use super::ArgumentType;
 
+#[derive(Clone)]
 pub struct BoolArgumentType;
 
 impl<S> ArgumentType<S> for BoolArgumentType {
@@ -15,14 +16,14 @@ impl<S> ArgumentType<S> for BoolArgumentType {
     fn parse(
         &self,
         reader: &mut crate::string_reader::StringReader,
-    ) -> Result<Self::Type, BrigadierError> {
-        return reader.read_boolean();
+    ) -> Result<Self::Type, CommandSyntaxException> {
+        reader.read_boolean()
     }
 
     #[allow(unused_variables)]
     fn list_suggestions(
         &self,
-        context: CommandContext<S>,
+        context: &CommandContext<S>,
         builder: &mut SuggestionsBuilder,
     ) -> super::Suggestions {
         if "true".starts_with(builder.get_remaining_lower_case()) {
@@ -31,7 +32,7 @@ impl<S> ArgumentType<S> for BoolArgumentType {
         if "false".starts_with(builder.get_remaining_lower_case()) {
             builder.suggest("false");
         }
-        return builder.build(); // TODO: build_future()
+        builder.build()
     }
 
     fn get_examples(&self) -> Vec<String> {
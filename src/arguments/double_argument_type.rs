@@ -1,9 +1,10 @@
 use std::fmt::Display;
 
-use crate::error::BrigadierError;
+use crate::{error::BrigadierError, exceptions::CommandSyntaxException};
 
 use super::ArgumentType;
 
+#[derive(Clone)]
 pub struct DoubleArgumentType {
     min: f64,
     max: f64,
@@ -27,13 +28,19 @@ impl DoubleArgumentType {
     }
 }
 
+impl Default for DoubleArgumentType {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<S> ArgumentType<S> for DoubleArgumentType {
     type Type = f64;
 
     fn parse(
         &self,
         reader: &mut crate::string_reader::StringReader,
-    ) -> Result<Self::Type, BrigadierError> {
+    ) -> Result<Self::Type, CommandSyntaxException> {
         let cursor = reader.get_cursor();
         let result = reader.read_double()?;
 
@@ -42,16 +49,18 @@ impl<S> ArgumentType<S> for DoubleArgumentType {
             return Err(BrigadierError::DoubleTooLow {
                 result,
                 min: self.min,
-            });
+            }
+            .create_with_context(reader));
         } else if result > self.max {
             reader.set_cursor(cursor);
             return Err(BrigadierError::DoubleTooHigh {
                 result,
                 max: self.max,
-            });
+            }
+            .create_with_context(reader));
         }
 
-        return Ok(result);
+        Ok(result)
     }
 
     fn get_examples(&self) -> Vec<String> {
@@ -1,9 +1,10 @@
 use std::fmt::Display;
 
-use crate::error::BrigadierError;
+use crate::{error::BrigadierError, exceptions::CommandSyntaxException};
 
 use super::ArgumentType;
 
+#[derive(Clone)]
 pub struct FloatArgumentType {
     min: f32,
     max: f32,
@@ -27,13 +28,19 @@ impl FloatArgumentType {
     }
 }
 
+impl Default for FloatArgumentType {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<S> ArgumentType<S> for FloatArgumentType {
     type Type = f32;
 
     fn parse(
         &self,
         reader: &mut crate::string_reader::StringReader,
-    ) -> Result<Self::Type, BrigadierError> {
+    ) -> Result<Self::Type, CommandSyntaxException> {
         let cursor = reader.get_cursor();
         let result = reader.read_float()?;
 
@@ -42,16 +49,18 @@ impl<S> ArgumentType<S> for FloatArgumentType {
             return Err(BrigadierError::FloatTooLow {
                 result,
                 min: self.min,
-            });
+            }
+            .create_with_context(reader));
         } else if result > self.max {
             reader.set_cursor(cursor);
             return Err(BrigadierError::FloatTooHigh {
                 result,
                 max: self.max,
-            });
+            }
+            .create_with_context(reader));
         }
 
-        return Ok(result);
+        Ok(result)
     }
 
     fn get_examples(&self) -> Vec<String> {
@@ -1,9 +1,10 @@
 use std::fmt::Display;
 
-use crate::error::BrigadierError;
+use crate::{error::BrigadierError, exceptions::CommandSyntaxException};
 
 use super::ArgumentType;
 
+#[derive(Clone)]
 pub struct IntegerArgumentType {
     min: i32,
     max: i32,
@@ -28,13 +29,19 @@ impl IntegerArgumentType {
     }
 }
 
+impl Default for IntegerArgumentType {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<S> ArgumentType<S> for IntegerArgumentType {
     type Type = i32;
 
     fn parse(
         &self,
         reader: &mut crate::string_reader::StringReader,
-    ) -> Result<Self::Type, BrigadierError> {
+    ) -> Result<Self::Type, CommandSyntaxException> {
         let cursor = reader.get_cursor();
         let result = reader.read_int()?;
 
@@ -43,16 +50,18 @@ impl<S> ArgumentType<S> for IntegerArgumentType {
             return Err(BrigadierError::IntegerTooLow {
                 result,
                 min: self.min,
-            });
+            }
+            .create_with_context(reader));
         } else if result > self.max {
             reader.set_cursor(cursor);
             return Err(BrigadierError::IntegerTooHigh {
                 result,
                 max: self.max,
-            });
+            }
+            .create_with_context(reader));
         }
 
-        return Ok(result);
+        Ok(result)
     }
 
     fn get_examples(&self) -> Vec<String> {
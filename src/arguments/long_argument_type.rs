@@ -1,9 +1,10 @@
 use std::fmt::Display;
 
-use crate::error::BrigadierError;
+use crate::{error::BrigadierError, exceptions::CommandSyntaxException};
 
 use super::ArgumentType;
 
+#[derive(Clone)]
 pub struct LongArgumentType {
     min: i64,
     max: i64,
@@ -28,13 +29,19 @@ impl LongArgumentType {
     }
 }
 
+impl Default for LongArgumentType {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<S> ArgumentType<S> for LongArgumentType {
     type Type = i64;
 
     fn parse(
         &self,
         reader: &mut crate::string_reader::StringReader,
-    ) -> Result<Self::Type, BrigadierError> {
+    ) -> Result<Self::Type, CommandSyntaxException> {
         let cursor = reader.get_cursor();
         let result = reader.read_long()?;
 
@@ -43,16 +50,18 @@ impl<S> ArgumentType<S> for LongArgumentType {
             return Err(BrigadierError::LongTooLow {
                 result,
                 min: self.min,
-            });
+            }
+            .create_with_context(reader));
         } else if result > self.max {
             reader.set_cursor(cursor);
             return Err(BrigadierError::LongTooHigh {
                 result,
                 max: self.max,
-            });
+            }
+            .create_with_context(reader));
         }
 
-        return Ok(result);
+        Ok(result)
     }
 
     fn get_examples(&self) -> Vec<String> {
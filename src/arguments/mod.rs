@@ -12,24 +12,24 @@ pub use integer_argument_type::IntegerArgumentType;
 pub use long_argument_type::LongArgumentType;
 pub use string_argument_type::StringArgumentType;
 
-use std::fmt::Display;
+use std::{any::Any, fmt::Display};
 
 use crate::{
     context::command_context::CommandContext,
-    error::BrigadierError,
+    exceptions::CommandSyntaxException,
     string_reader::StringReader,
     suggestion::{suggestions::Suggestions, suggestions_builder::SuggestionsBuilder},
 };
 
-pub trait ArgumentType<S>: Display {
+pub trait ArgumentType<S>: Display + Send + Sync {
     type Type;
 
-    fn parse(&self, reader: &mut StringReader) -> Result<Self::Type, BrigadierError>;
+    fn parse(&self, reader: &mut StringReader) -> Result<Self::Type, CommandSyntaxException>;
 
     #[allow(unused_variables)]
     fn list_suggestions(
         &self,
-        context: CommandContext<S>,
+        context: &CommandContext<S>,
         builder: &mut SuggestionsBuilder,
     ) -> Suggestions {
         Suggestions::empty()
@@ -39,3 +39,66 @@ pub trait ArgumentType<S>: Display {
         Vec::new()
     }
 }
+
+impl<S, A: ArgumentType<S> + ?Sized> ArgumentType<S> for Box<A> {
+    type Type = A::Type;
+
+    fn parse(&self, reader: &mut StringReader) -> Result<Self::Type, CommandSyntaxException> {
+        (**self).parse(reader)
+    }
+
+    fn list_suggestions(
+        &self,
+        context: &CommandContext<S>,
+        builder: &mut SuggestionsBuilder,
+    ) -> Suggestions {
+        (**self).list_suggestions(context, builder)
+    }
+
+    fn get_examples(&self) -> Vec<String> {
+        (**self).get_examples()
+    }
+}
+
+/// An [`ArgumentType`] whose parsed value is boxed, so nodes of any argument type can share one
+/// tree. Every `ArgumentType` with a `Send + Sync + 'static` value implements it.
+pub trait ErasedArgumentType<S>: Display + Send + Sync {
+    fn parse_erased(
+        &self,
+        reader: &mut StringReader,
+    ) -> Result<Box<dyn Any + Send + Sync>, CommandSyntaxException>;
+
+    fn list_suggestions(
+        &self,
+        context: &CommandContext<S>,
+        builder: &mut SuggestionsBuilder,
+    ) -> Suggestions;
+
+    fn get_examples(&self) -> Vec<String>;
+}
+
+impl<S, A> ErasedArgumentType<S> for A
+where
+    A: ArgumentType<S>,
+    A::Type: Send + Sync + 'static,
+{
+    fn parse_erased(
+        &self,
+        reader: &mut StringReader,
+    ) -> Result<Box<dyn Any + Send + Sync>, CommandSyntaxException> {
+        let result = self.parse(reader)?;
+        Ok(Box::new(result))
+    }
+
+    fn list_suggestions(
+        &self,
+        context: &CommandContext<S>,
+        builder: &mut SuggestionsBuilder,
+    ) -> Suggestions {
+        ArgumentType::list_suggestions(self, context, builder)
+    }
+
+    fn get_examples(&self) -> Vec<String> {
+        ArgumentType::get_examples(self)
+    }
+}
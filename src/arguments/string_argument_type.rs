@@ -1,10 +1,11 @@
 use std::fmt::Display;
 
-use crate::{error::BrigadierError, string_reader::StringReader};
+use crate::{exceptions::CommandSyntaxException, string_reader::StringReader};
 
 use super::ArgumentType;
 
 #[allow(dead_code)]
+#[derive(Clone)]
 pub enum StringType {
     Word,
     Quotable,
@@ -29,6 +30,7 @@ impl StringType {
     }
 }
 
+#[derive(Clone)]
 pub struct StringArgumentType {
     string_type: StringType,
 }
@@ -47,7 +49,7 @@ impl StringArgumentType {
 impl<S> ArgumentType<S> for StringArgumentType {
     type Type = String;
 
-    fn parse(&self, reader: &mut StringReader) -> Result<Self::Type, BrigadierError> {
+    fn parse(&self, reader: &mut StringReader) -> Result<Self::Type, CommandSyntaxException> {
         match self.string_type {
             StringType::Word => reader.read_unqoted_string(),
             StringType::Quotable => reader.read_string(),
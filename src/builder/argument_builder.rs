@@ -0,0 +1,135 @@
+use crate::{
+    command::Command,
+    context::command_context::CommandContext,
+    redirect_modifier::RedirectModifier,
+    single_redirect_modifier::SingleRedirectModifier,
+    tree::{
+        command_node::{CommandNode, Requirement},
+        node_id::NodeId,
+    },
+};
+
+/// The builders of a node's children, in the order they were added.
+pub type Arguments<S> = Vec<Box<dyn ArgumentBuilder<S>>>;
+
+/// The state shared by every builder, like the fields of Mojang's `ArgumentBuilder` base class.
+pub struct ArgumentBuilderBase<S> {
+    pub(crate) arguments: Arguments<S>,
+    pub(crate) command: Option<Command<S>>,
+    pub(crate) requirement: Option<Requirement<S>>,
+    pub(crate) target: Option<NodeId>,
+    pub(crate) modifier: Option<Box<dyn RedirectModifier<S>>>,
+    pub(crate) forks: bool,
+}
+
+impl<S> ArgumentBuilderBase<S> {
+    pub fn new() -> Self {
+        Self {
+            arguments: Vec::new(),
+            command: None,
+            requirement: None,
+            target: None,
+            modifier: None,
+            forks: false,
+        }
+    }
+}
+
+impl<S> Default for ArgumentBuilderBase<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Anything that can be added as a child with `then`.
+///
+/// Builders only provide access to their [`ArgumentBuilderBase`]; the shared builder methods are
+/// implemented here once for all of them.
+pub trait ArgumentBuilder<S> {
+    fn get_base(&self) -> &ArgumentBuilderBase<S>;
+
+    fn get_base_mut(&mut self) -> &mut ArgumentBuilderBase<S>;
+
+    /// Splits the builder into its node and the builders of that node's children.
+    fn into_parts(self: Box<Self>) -> (Box<dyn CommandNode<S>>, Arguments<S>);
+
+    fn then(mut self, argument: impl ArgumentBuilder<S> + 'static) -> Self
+    where
+        Self: Sized,
+    {
+        if self.get_base().target.is_some() {
+            panic!("Cannot add children to a redirected node"); // todo: tracing error or smth
+        }
+
+        self.get_base_mut().arguments.push(Box::new(argument));
+        self
+    }
+
+    fn get_arguments(&self) -> &[Box<dyn ArgumentBuilder<S>>] {
+        &self.get_base().arguments
+    }
+
+    fn executes(mut self, command: Command<S>) -> Self
+    where
+        Self: Sized,
+    {
+        self.get_base_mut().command = Some(command);
+        self
+    }
+
+    fn requires(mut self, requirement: Requirement<S>) -> Self
+    where
+        Self: Sized,
+    {
+        self.get_base_mut().requirement = Some(requirement);
+        self
+    }
+
+    fn redirect(self, target: NodeId) -> Self
+    where
+        Self: Sized,
+    {
+        self.forward(target, None, false)
+    }
+
+    fn redirect_with(
+        self,
+        target: NodeId,
+        modifier: impl SingleRedirectModifier<S> + 'static,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        let modifier = move |context: &CommandContext<S>| -> Result<Vec<S>, _> {
+            modifier.apply(context).map(|source| vec![source])
+        };
+        self.forward(target, Some(Box::new(modifier)), false)
+    }
+
+    fn fork(self, target: NodeId, modifier: impl RedirectModifier<S> + 'static) -> Self
+    where
+        Self: Sized,
+    {
+        self.forward(target, Some(Box::new(modifier)), true)
+    }
+
+    fn forward(
+        mut self,
+        target: NodeId,
+        modifier: Option<Box<dyn RedirectModifier<S>>>,
+        fork: bool,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        let base = self.get_base_mut();
+        if !base.arguments.is_empty() {
+            panic!("Cannot forward a node with children");
+        }
+
+        base.target = Some(target);
+        base.modifier = modifier;
+        base.forks = fork;
+        self
+    }
+}
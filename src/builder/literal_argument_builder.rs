@@ -1,60 +1,50 @@
-use crate::{
-    command::Command,
-    redirect_modifier::RedirectModifier,
-    tree::{
-        command_node::CommandNode, literal_command_node::LiteralCommandNode,
-        root_command_node::RootCommandNode,
-    },
-};
+use crate::tree::{command_node::CommandNode, literal_command_node::LiteralCommandNode};
+
+use super::argument_builder::{ArgumentBuilder, ArgumentBuilderBase, Arguments};
 
 pub struct LiteralArgumentBuilder<S> {
     pub(crate) literal: String,
-    pub(crate) arguments: RootCommandNode<S>,
-    pub(crate) command: Option<Command<S>>,
-    pub(crate) requirement: Option<Box<dyn Fn(&S) -> bool>>,
-    pub(crate) target: Option<Box<dyn CommandNode<S>>>,
-    pub(crate) modifier: Option<Box<dyn RedirectModifier<S>>>,
-    pub(crate) forks: bool,
+    pub(crate) base: ArgumentBuilderBase<S>,
+}
+
+/// Shorthand for [`LiteralArgumentBuilder::new`], mirroring `LiteralArgumentBuilder.literal(..)`.
+pub fn literal<S>(literal: &str) -> LiteralArgumentBuilder<S> {
+    LiteralArgumentBuilder::new(literal.to_owned())
 }
 
 impl<S> LiteralArgumentBuilder<S> {
     pub fn new(literal: String) -> Self {
         Self {
             literal,
-            arguments: RootCommandNode::new(),
-            command: None,
-            requirement: None,
-            target: None,
-            modifier: None,
-            forks: false,
-        }
-    }
-
-    pub fn then(&mut self, argument: Box<dyn CommandNode<S>>) -> &mut Self {
-        if self.target.is_some() {
-            panic!("Cannot add children to a redirected node"); // todo: tracing error or smth
+            base: ArgumentBuilderBase::new(),
         }
-
-        self.arguments.add_child(argument);
-        self
     }
 
-    pub fn get_arguments(&self) -> Vec<Box<dyn CommandNode<S>>> {
-        self.arguments.get_children()
+    /// Builds the node without its children, which only get created once the builder is
+    /// registered with a [`CommandDispatcher`](crate::command_dispatcher::CommandDispatcher).
+    pub fn build(self) -> LiteralCommandNode<S> {
+        LiteralCommandNode::new(
+            self.literal,
+            self.base.command,
+            self.base.requirement.unwrap_or_else(|| Box::new(|_| true)),
+            self.base.target,
+            self.base.modifier,
+            self.base.forks,
+        )
     }
+}
 
-    /// this.executes(..)
-    pub fn with_command(&mut self, command: Command<S>) -> &mut Self {
-        self.command = Some(command);
-        self
+impl<S: 'static> ArgumentBuilder<S> for LiteralArgumentBuilder<S> {
+    fn get_base(&self) -> &ArgumentBuilderBase<S> {
+        &self.base
     }
 
-    pub fn with_requirement(&mut self, requirement: Box<dyn Fn(&S) -> bool>) -> &mut Self {
-        self.requirement = Some(requirement);
-        self
+    fn get_base_mut(&mut self) -> &mut ArgumentBuilderBase<S> {
+        &mut self.base
     }
 
-    pub fn build() -> LiteralCommandNode<S> {
-        todo!()
+    fn into_parts(mut self: Box<Self>) -> (Box<dyn CommandNode<S>>, Arguments<S>) {
+        let arguments = std::mem::take(&mut self.base.arguments);
+        (Box::new(self.build()), arguments)
     }
 }
@@ -1 +1,3 @@
+pub mod argument_builder;
 pub mod literal_argument_builder;
+pub mod required_argument_builder;
@@ -0,0 +1,85 @@
+use crate::{
+    arguments::ArgumentType,
+    suggestion::suggestion_provider::SuggestionProvider,
+    tree::{argument_command_node::ArgumentCommandNode, command_node::CommandNode},
+};
+
+use super::argument_builder::{ArgumentBuilder, ArgumentBuilderBase, Arguments};
+
+pub struct RequiredArgumentBuilder<S, T> {
+    pub(crate) name: String,
+    pub(crate) argument_type: Box<dyn ArgumentType<S, Type = T>>,
+    pub(crate) suggestions_provider: Option<Box<dyn SuggestionProvider<S>>>,
+    pub(crate) base: ArgumentBuilderBase<S>,
+}
+
+/// Shorthand for [`RequiredArgumentBuilder::new`], mirroring `RequiredArgumentBuilder.argument(..)`.
+pub fn argument<S, T>(
+    name: &str,
+    argument_type: impl ArgumentType<S, Type = T> + 'static,
+) -> RequiredArgumentBuilder<S, T> {
+    RequiredArgumentBuilder::new(name.to_owned(), Box::new(argument_type))
+}
+
+impl<S, T> RequiredArgumentBuilder<S, T> {
+    pub fn new(name: String, argument_type: Box<dyn ArgumentType<S, Type = T>>) -> Self {
+        Self {
+            name,
+            argument_type,
+            suggestions_provider: None,
+            base: ArgumentBuilderBase::new(),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_type(&self) -> &dyn ArgumentType<S, Type = T> {
+        self.argument_type.as_ref()
+    }
+
+    /// Replaces the argument type's own suggestions with those of `provider`.
+    pub fn suggests(mut self, provider: impl SuggestionProvider<S> + 'static) -> Self {
+        self.suggestions_provider = Some(Box::new(provider));
+        self
+    }
+
+    pub fn get_suggestions_provider(&self) -> Option<&dyn SuggestionProvider<S>> {
+        self.suggestions_provider.as_deref()
+    }
+
+    /// Builds the node without its children, which only get created once the builder is
+    /// registered with a [`CommandDispatcher`](crate::command_dispatcher::CommandDispatcher).
+    pub fn build(self) -> ArgumentCommandNode<S>
+    where
+        S: 'static,
+        T: Send + Sync + 'static,
+    {
+        ArgumentCommandNode::new(
+            self.name,
+            Box::new(self.argument_type),
+            self.base.command,
+            self.base.requirement.unwrap_or_else(|| Box::new(|_| true)),
+            self.base.target,
+            self.base.modifier,
+            self.base.forks,
+            self.suggestions_provider,
+        )
+    }
+}
+
+impl<S: 'static, T: Send + Sync + 'static> ArgumentBuilder<S> for RequiredArgumentBuilder<S, T> {
+    fn get_base(&self) -> &ArgumentBuilderBase<S> {
+        &self.base
+    }
+
+    fn get_base_mut(&mut self) -> &mut ArgumentBuilderBase<S> {
+        &mut self.base
+    }
+
+    fn into_parts(mut self: Box<Self>) -> (Box<dyn CommandNode<S>>, Arguments<S>) {
+        let arguments = std::mem::take(&mut self.base.arguments);
+        (Box::new(self.build()), arguments)
+    }
+}
@@ -1,27 +1,76 @@
-use crate::{context::command_context::CommandContext, error::BrigadierError};
+use std::sync::Arc;
+
+use crate::{
+    context::command_context::CommandContext, error::BrigadierError,
+    exceptions::CommandSyntaxException, future::BoxFuture,
+};
 
 pub const SINGLE_SUCCESS: i32 = 1;
 
-//pub trait Command<S>: 'static + Send + Sync + Clone {
-//    fn run(&self, context: CommandContext<S>) -> Result<i32, BrigadierError>;
-//}
+type SyncCommandFn<S> =
+    dyn Fn(&CommandContext<S>) -> Result<i32, CommandSyntaxException> + Send + Sync;
+type AsyncCommandFn<S> = dyn for<'a, 'b> Fn(&'a CommandContext<'b, S>) -> BoxFuture<'a, Result<i32, CommandSyntaxException>>
+    + Send
+    + Sync;
+
+enum Handler<S> {
+    Sync(Arc<SyncCommandFn<S>>),
+    Async(Arc<AsyncCommandFn<S>>),
+}
 
-pub struct Command<S>(fn(context: CommandContext<S>) -> Result<i32, BrigadierError>);
+/// The handler run when a command is executed. Cloning only clones the handle, so a command can
+/// capture state like a connection pool or configuration.
+pub struct Command<S>(Handler<S>);
 
 impl<S> Command<S> {
-    pub fn new(f: fn(context: CommandContext<S>) -> Result<i32, BrigadierError>) -> Self {
-        Self(f)
+    pub fn new(
+        f: impl Fn(&CommandContext<S>) -> Result<i32, CommandSyntaxException> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Handler::Sync(Arc::new(f)))
     }
 
-    pub fn run(&self, context: CommandContext<S>) -> Result<i32, BrigadierError> {
-        (self.0)(context)
+    /// A command whose handler returns a future. It can only be run by
+    /// [`CommandDispatcher::execute_async`](crate::command_dispatcher::CommandDispatcher::execute_async).
+    pub fn new_async(
+        f: impl for<'a, 'b> Fn(
+                &'a CommandContext<'b, S>,
+            ) -> BoxFuture<'a, Result<i32, CommandSyntaxException>>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self(Handler::Async(Arc::new(f)))
+    }
+
+    pub fn is_async(&self) -> bool {
+        matches!(self.0, Handler::Async(_))
+    }
+
+    /// Runs a synchronous command. Async commands fail with
+    /// [`BrigadierError::RequiresAsyncExecution`].
+    pub fn run(&self, context: &CommandContext<S>) -> Result<i32, CommandSyntaxException> {
+        match &self.0 {
+            Handler::Sync(f) => f(context),
+            Handler::Async(_) => Err(BrigadierError::RequiresAsyncExecution.create()),
+        }
     }
-}
 
-impl<S> Copy for Command<S> {}
+    pub fn run_async<'a>(
+        &'a self,
+        context: &'a CommandContext<S>,
+    ) -> BoxFuture<'a, Result<i32, CommandSyntaxException>> {
+        match &self.0 {
+            Handler::Sync(f) => Box::pin(std::future::ready(f(context))),
+            Handler::Async(f) => f(context),
+        }
+    }
+}
 
 impl<S> Clone for Command<S> {
     fn clone(&self) -> Self {
-        *self
+        Self(match &self.0 {
+            Handler::Sync(f) => Handler::Sync(f.clone()),
+            Handler::Async(f) => Handler::Async(f.clone()),
+        })
     }
 }
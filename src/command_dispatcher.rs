@@ -1,35 +1,2229 @@
-use crate::{error::BrigadierError, string_reader::StringReader};
+use std::sync::Arc;
+
+use crate::{
+    ambiguity_consumer::AmbiguityConsumer,
+    builder::{
+        argument_builder::ArgumentBuilder, literal_argument_builder::LiteralArgumentBuilder,
+    },
+    command::Command,
+    context::{command_context::CommandContext, command_context_builder::CommandContextBuilder},
+    error::BrigadierError,
+    exceptions::CommandSyntaxException,
+    future::now_or_never,
+    parse_results::ParseResults,
+    reader_syntax::ReaderSyntax,
+    redirect_modifier::RedirectModifier,
+    result_consumer::ResultConsumer,
+    string_reader::StringReader,
+    suggestion::{suggestions::Suggestions, suggestions_builder::SuggestionsBuilder},
+    tree::{command_node::CommandNode, node_id::NodeId, root_command_node::RootCommandNode},
+};
 
 const ARGUMENT_SEPARATOR: &str = " ";
+const ARGUMENT_SEPARATOR_CHAR: char = ' ';
 const USAGE_OPTIONAL_OPEN: &str = "[";
 const USAGE_OPTIONAL_CLOSE: &str = "]";
-const USAGE_REQUIRED_OPEN: &str = "<";
-const USAGE_REQUIRED_CLOSE: &str = ">";
+const USAGE_REQUIRED_OPEN: &str = "(";
+const USAGE_REQUIRED_CLOSE: &str = ")";
 const USAGE_OR: &str = "|";
 
+const ROOT: NodeId = NodeId(0);
+
+/// Owns the command tree, storing every node in one arena addressed by [`NodeId`].
 pub struct CommandDispatcher<S> {
-    // phantom marker for now
-    _source: std::marker::PhantomData<S>,
+    nodes: Vec<Box<dyn CommandNode<S>>>,
+    consumer: Box<dyn ResultConsumer<S>>,
+    syntax: Option<Arc<ReaderSyntax>>,
 }
 
 impl<S> CommandDispatcher<S> {
+    pub fn get_root(&self) -> NodeId {
+        ROOT
+    }
+
+    /// The node `id` refers to.
+    ///
+    /// Panics if `id` was not handed out by this dispatcher.
+    pub fn get_node(&self, id: NodeId) -> &dyn CommandNode<S> {
+        self.nodes[id.0].as_ref()
+    }
+}
+
+impl<S: 'static> CommandDispatcher<S> {
     pub fn new() -> CommandDispatcher<S> {
         CommandDispatcher {
-            _source: std::marker::PhantomData,
+            nodes: vec![Box::new(RootCommandNode::new())],
+            consumer: Box::new(|_: &CommandContext<S>, _, _| {}),
+            syntax: None,
+        }
+    }
+
+    /// Sets the syntax every command is read with, replacing the characters of any reader passed
+    /// in. Number and escape modes enabled on that reader are kept.
+    pub fn set_syntax(&mut self, syntax: ReaderSyntax) {
+        self.syntax = Some(Arc::new(syntax));
+    }
+
+    pub fn get_syntax(&self) -> Option<&ReaderSyntax> {
+        self.syntax.as_deref()
+    }
+
+    /// Sets the callback that is told the outcome of every context executed, including each
+    /// branch of a fork.
+    pub fn set_consumer(&mut self, consumer: impl ResultConsumer<S> + 'static) {
+        self.consumer = Box::new(consumer);
+    }
+
+    /// Adds `command` to the root of the tree, merging it with an existing literal of the same
+    /// name, and returns the node as it now exists in the tree.
+    pub fn register(&mut self, command: LiteralArgumentBuilder<S>) -> NodeId {
+        self.add_child(ROOT, Box::new(command))
+    }
+
+    /// Builds `builder` and its children under `parent`. A child with the same name as an
+    /// existing one is merged onto it: its command replaces the existing one and its children
+    /// are added recursively.
+    fn add_child(&mut self, parent: NodeId, builder: Box<dyn ArgumentBuilder<S>>) -> NodeId {
+        let (node, arguments) = builder.into_parts();
+        let id = match self.get_node(parent).get_child(node.get_name()) {
+            Some(existing) => {
+                // We've found something to merge onto
+                if let Some(command) = node.get_command() {
+                    self.nodes[existing.0].set_command(command.clone());
+                }
+                existing
+            }
+            None => {
+                let id = NodeId(self.nodes.len());
+                let name = node.get_name().to_owned();
+                self.nodes.push(node);
+                self.nodes[parent.0].add_child(name, id);
+                id
+            }
+        };
+
+        for argument in arguments {
+            self.add_child(id, argument);
+        }
+        id
+    }
+
+    /// The names of the nodes leading from the root to `target`, or an empty list if `target`
+    /// is not part of this dispatcher's tree.
+    pub fn get_path(&self, target: NodeId) -> Vec<String> {
+        let mut path = Vec::new();
+        self.find_path(ROOT, target, &mut path);
+        path
+    }
+
+    fn find_path(&self, node: NodeId, target: NodeId, path: &mut Vec<String>) -> bool {
+        for child in self.get_node(node).get_children() {
+            path.push(self.get_node(child).get_name().to_owned());
+            if child == target || self.find_path(child, target, path) {
+                return true;
+            }
+            path.pop();
+        }
+        false
+    }
+
+    /// Follows `path` child by child from the root, returning the node it ends at.
+    pub fn find_node(&self, path: &[&str]) -> Option<NodeId> {
+        let mut node = ROOT;
+        for name in path {
+            node = self.get_node(node).get_child(name)?;
+        }
+        Some(node)
+    }
+
+    /// Suggestions for completing the input of `parse` at `cursor`.
+    ///
+    /// Every child of the node before the cursor is asked for suggestions starting where that
+    /// child's input begins, and the results are merged into one list. Async suggestion
+    /// providers fail with [`BrigadierError::RequiresAsyncExecution`].
+    pub fn get_completion_suggestions(
+        &self,
+        parse: &ParseResults<S>,
+        cursor: usize,
+    ) -> Result<Suggestions, CommandSyntaxException>
+    where
+        S: Clone,
+    {
+        now_or_never(self.get_completion_suggestions_with(parse, cursor, &SyncExecution))
+            .expect("synchronous suggestions never wait")
+    }
+
+    /// The async counterpart of [`CommandDispatcher::get_completion_suggestions`], which also
+    /// awaits async suggestion providers. Providers are awaited one after another.
+    pub async fn get_completion_suggestions_async(
+        &self,
+        parse: &ParseResults<'_, S>,
+        cursor: usize,
+    ) -> Result<Suggestions, CommandSyntaxException>
+    where
+        S: Clone + Send + Sync,
+    {
+        self.get_completion_suggestions_with(parse, cursor, &AsyncExecution)
+            .await
+    }
+
+    async fn get_completion_suggestions_with(
+        &self,
+        parse: &ParseResults<'_, S>,
+        cursor: usize,
+        execution: &impl Execution<S>,
+    ) -> Result<Suggestions, CommandSyntaxException>
+    where
+        S: Clone,
+    {
+        let full_input = parse.get_reader().get_string();
+        let cursor = cursor.min(full_input.len());
+        let context = parse.get_context();
+        let Some(node_before_cursor) = context.find_suggestion_context(cursor) else {
+            return Ok(Suggestions::empty());
+        };
+        let parent = node_before_cursor.parent;
+        let start = std::cmp::min(node_before_cursor.start_pos, cursor);
+
+        let truncated_input = &full_input[..full_input.floor_char_boundary(cursor)];
+        let truncated_context = context.build(truncated_input);
+        let mut suggestions = Vec::new();
+        for node in self.get_node(parent).get_children() {
+            let mut builder = SuggestionsBuilder::new(truncated_input, start);
+            let node = self.get_node(node);
+            suggestions.push(
+                execution
+                    .list_suggestions(node, &truncated_context, &mut builder)
+                    .await?,
+            );
+        }
+
+        Ok(Suggestions::merge(full_input, suggestions))
+    }
+
+    /// Reports every pair of sibling nodes where one accepts an example input of the other.
+    ///
+    /// Each ambiguity is passed to `consumer` with the parent, the node whose examples were
+    /// tried, the sibling that also accepted them and the ambiguous inputs.
+    pub fn find_ambiguities(&self, consumer: &mut dyn AmbiguityConsumer<S>) {
+        let syntax = self.syntax.clone().unwrap_or_default();
+        self.find_node_ambiguities(ROOT, &syntax, consumer);
+    }
+
+    fn find_node_ambiguities(
+        &self,
+        node: NodeId,
+        syntax: &Arc<ReaderSyntax>,
+        consumer: &mut dyn AmbiguityConsumer<S>,
+    ) {
+        let children = self.get_node(node).get_children();
+        for &child_id in &children {
+            let child = self.get_node(child_id);
+            for &sibling_id in &children {
+                if child_id == sibling_id {
+                    continue;
+                }
+                let sibling = self.get_node(sibling_id);
+
+                let mut matches: Vec<String> = Vec::new();
+                for input in child.get_examples() {
+                    if sibling.is_valid_input(&input, syntax) && !matches.contains(&input) {
+                        matches.push(input);
+                    }
+                }
+
+                if !matches.is_empty() {
+                    consumer.ambiguous(self.get_node(node), child, sibling, &matches);
+                }
+            }
+
+            self.find_node_ambiguities(child_id, syntax, consumer);
         }
     }
 
-    pub fn execute<'a>(
+    /// Every usage of `node` that ends in an executable command, one string per command.
+    ///
+    /// With `restricted`, nodes that `source` cannot use are left out.
+    pub fn get_all_usage(&self, node: NodeId, source: &S, restricted: bool) -> Vec<String> {
+        let mut result = Vec::new();
+        self.get_all_usage_recursive(node, source, &mut result, "", restricted);
+        result
+    }
+
+    fn get_all_usage_recursive(
         &self,
-        command: impl Into<StringReader<'a>>,
+        node: NodeId,
+        source: &S,
+        result: &mut Vec<String>,
+        prefix: &str,
+        restricted: bool,
+    ) {
+        let node = self.get_node(node);
+        if restricted && !node.can_use(source) {
+            return;
+        }
+
+        if node.get_command().is_some() {
+            result.push(prefix.to_owned());
+        }
+
+        if let Some(redirect) = node.get_redirect() {
+            let redirect = self.get_redirect_usage(redirect);
+            if prefix.is_empty() {
+                result.push(node.get_usage_text() + ARGUMENT_SEPARATOR + &redirect);
+            } else {
+                result.push(prefix.to_owned() + ARGUMENT_SEPARATOR + &redirect);
+            }
+        } else {
+            for child in node.get_children() {
+                let usage = self.get_node(child).get_usage_text();
+                let prefix = if prefix.is_empty() {
+                    usage
+                } else {
+                    prefix.to_owned() + ARGUMENT_SEPARATOR + &usage
+                };
+                self.get_all_usage_recursive(child, source, result, &prefix, restricted);
+            }
+        }
+    }
+
+    /// A condensed usage string for each child of `node` that `source` can use.
+    ///
+    /// Optional parts are wrapped in `[]`, alternatives are joined with `|` inside `()` and
+    /// redirects are shown as `-> target`.
+    pub fn get_smart_usage(&self, node: NodeId, source: &S) -> Vec<(NodeId, String)> {
+        let node = self.get_node(node);
+        let optional = node.get_command().is_some();
+        node.get_children()
+            .into_iter()
+            .filter_map(|child| {
+                self.get_smart_usage_recursive(child, source, optional, false)
+                    .map(|usage| (child, usage))
+            })
+            .collect()
+    }
+
+    fn get_smart_usage_recursive(
+        &self,
+        node: NodeId,
+        source: &S,
+        optional: bool,
+        deep: bool,
+    ) -> Option<String> {
+        let node = self.get_node(node);
+        if !node.can_use(source) {
+            return None;
+        }
+
+        let this = if optional {
+            USAGE_OPTIONAL_OPEN.to_owned() + &node.get_usage_text() + USAGE_OPTIONAL_CLOSE
+        } else {
+            node.get_usage_text()
+        };
+        if deep {
+            return Some(this);
+        }
+
+        let child_optional = node.get_command().is_some();
+        let (open, close) = if child_optional {
+            (USAGE_OPTIONAL_OPEN, USAGE_OPTIONAL_CLOSE)
+        } else {
+            (USAGE_REQUIRED_OPEN, USAGE_REQUIRED_CLOSE)
+        };
+
+        if let Some(redirect) = node.get_redirect() {
+            return Some(this + ARGUMENT_SEPARATOR + &self.get_redirect_usage(redirect));
+        }
+
+        let children = node
+            .get_children()
+            .into_iter()
+            .filter(|&child| self.get_node(child).can_use(source))
+            .collect::<Vec<_>>();
+        if children.len() == 1 {
+            let usage =
+                self.get_smart_usage_recursive(children[0], source, child_optional, child_optional);
+            if let Some(usage) = usage {
+                return Some(this + ARGUMENT_SEPARATOR + &usage);
+            }
+        } else if children.len() > 1 {
+            let mut child_usage: Vec<String> = Vec::new();
+            for &child in &children {
+                if let Some(usage) =
+                    self.get_smart_usage_recursive(child, source, child_optional, true)
+                {
+                    if !child_usage.contains(&usage) {
+                        child_usage.push(usage);
+                    }
+                }
+            }
+
+            if child_usage.len() == 1 {
+                let usage = child_usage.remove(0);
+                let usage = if child_optional {
+                    USAGE_OPTIONAL_OPEN.to_owned() + &usage + USAGE_OPTIONAL_CLOSE
+                } else {
+                    usage
+                };
+                return Some(this + ARGUMENT_SEPARATOR + &usage);
+            } else if child_usage.len() > 1 {
+                let alternatives = children
+                    .iter()
+                    .map(|&child| self.get_node(child).get_usage_text())
+                    .collect::<Vec<_>>()
+                    .join(USAGE_OR);
+                return Some(this + ARGUMENT_SEPARATOR + open + &alternatives + close);
+            }
+        }
+
+        Some(this)
+    }
+
+    fn get_redirect_usage(&self, redirect: NodeId) -> String {
+        if redirect == ROOT {
+            "...".to_owned()
+        } else {
+            "-> ".to_owned() + &self.get_node(redirect).get_usage_text()
+        }
+    }
+
+    /// Parses `command` against the tree without executing it.
+    ///
+    /// Every child that could match is tried and the best branch is kept: one that consumed the
+    /// whole input wins over one that did not, and one without errors wins over one with errors.
+    /// When nothing matches, the returned results hold the error raised by each node tried.
+    pub fn parse(&self, command: impl Into<StringReader>, source: S) -> ParseResults<'_, S>
+    where
+        S: Clone,
+    {
+        let mut reader = command.into();
+        if let Some(syntax) = &self.syntax {
+            let syntax = syntax.with_modes_of(reader.get_syntax());
+            reader = reader.with_syntax(syntax);
+        }
+        let context = CommandContextBuilder::new(self, source, ROOT, reader.get_cursor());
+        self.parse_nodes(ROOT, &reader, context)
+    }
+
+    fn parse_nodes<'a>(
+        &'a self,
+        node: NodeId,
+        original_reader: &StringReader,
+        context_so_far: CommandContextBuilder<'a, S>,
+    ) -> ParseResults<'a, S>
+    where
+        S: Clone,
+    {
+        let source = context_so_far.get_source();
+        let mut errors = Vec::new();
+        let mut potentials: Vec<ParseResults<'a, S>> = Vec::new();
+        let cursor = original_reader.get_cursor();
+
+        for child_id in self.get_relevant_nodes(node, &mut original_reader.clone()) {
+            let child = self.get_node(child_id);
+            if !child.can_use(source) {
+                continue;
+            }
+            let mut context = context_so_far.clone();
+            let mut reader = original_reader.clone();
+
+            let parsed = child.parse(&mut reader, &mut context).and_then(|range| {
+                if reader.can_read_char() && reader.peek_char() != Ok(ARGUMENT_SEPARATOR_CHAR) {
+                    return Err(BrigadierError::DispatcherExpectedArgumentSeparator
+                        .create_with_context(&reader));
+                }
+                Ok(range)
+            });
+            match parsed {
+                Ok(range) => context.with_node(child_id, range),
+                Err(err) => {
+                    errors.push((child_id, err));
+                    reader.set_cursor(cursor);
+                    continue;
+                }
+            };
+
+            context.with_command(child.get_command().cloned());
+            let redirect = child.get_redirect();
+            if reader.can_read(if redirect.is_none() { 2 } else { 1 }) {
+                reader.skip();
+                if let Some(redirect) = redirect {
+                    let child_context = CommandContextBuilder::new(
+                        self,
+                        source.clone(),
+                        redirect,
+                        reader.get_cursor(),
+                    );
+                    let parse = self.parse_nodes(redirect, &reader, child_context);
+                    context.with_child(parse.context);
+                    return ParseResults::new(context, parse.reader, parse.exceptions);
+                }
+                potentials.push(self.parse_nodes(child_id, &reader, context));
+            } else {
+                potentials.push(ParseResults::new(context, reader, Vec::new()));
+            }
+        }
+
+        // sort_by is stable, so equally good branches keep the order they were tried in
+        potentials.sort_by(|a, b| {
+            let a_key = (
+                a.get_reader().can_read_char(),
+                !a.get_exceptions().is_empty(),
+            );
+            let b_key = (
+                b.get_reader().can_read_char(),
+                !b.get_exceptions().is_empty(),
+            );
+            a_key.cmp(&b_key)
+        });
+
+        match potentials.into_iter().next() {
+            Some(best) => best,
+            None => ParseResults::new(context_so_far, original_reader.clone(), errors),
+        }
+    }
+
+    /// The children of `node` worth trying for the next word of `input`: the literal matching it
+    /// exactly if there is one, otherwise every argument child.
+    fn get_relevant_nodes(&self, node: NodeId, input: &mut StringReader) -> Vec<NodeId> {
+        let (literals, arguments): (Vec<_>, Vec<_>) = self
+            .get_node(node)
+            .get_children()
+            .into_iter()
+            .partition(|&child| self.get_node(child).as_literal().is_some());
+
+        if literals.is_empty() {
+            return arguments;
+        }
+
+        let cursor = input.get_cursor();
+        while input.can_read_char() && input.peek_char() != Ok(ARGUMENT_SEPARATOR_CHAR) {
+            input.skip();
+        }
+        let end = input.get_cursor();
+        input.set_cursor(cursor);
+        let text = &input.get_string()[cursor..end];
+
+        match literals
+            .into_iter()
+            .find(|&literal| self.get_node(literal).get_name() == text)
+        {
+            Some(literal) => vec![literal],
+            None => arguments,
+        }
+    }
+
+    /// Parses and executes `command` as `source`.
+    ///
+    /// See [`CommandDispatcher::execute_parsed`] for how the result is computed.
+    pub fn execute(
+        &self,
+        command: impl Into<StringReader>,
+        source: S,
+    ) -> Result<i32, CommandSyntaxException>
+    where
+        S: Clone,
+    {
+        self.execute_parsed(self.parse(command, source))
+    }
+
+    /// Executes a command that was already parsed by [`CommandDispatcher::parse`].
+    ///
+    /// Redirects are followed: each redirect modifier is applied to its context and the rest of the
+    /// command runs once for every source it returns. If the command never forked, the sum of the
+    /// command results is returned and the first error is propagated. Once a fork has been passed,
+    /// errors in a branch are swallowed and the number of successful branches is returned instead.
+    /// Async commands and modifiers fail with [`BrigadierError::RequiresAsyncExecution`], which is
+    /// propagated even from a fork.
+    pub fn execute_parsed(&self, parse: ParseResults<S>) -> Result<i32, CommandSyntaxException>
+    where
+        S: Clone,
+    {
+        now_or_never(self.execute_parsed_with(parse, &SyncExecution))
+            .expect("synchronous execution never waits")
+    }
+
+    /// Parses and executes `command` as `source`, awaiting async commands and redirect modifiers.
+    ///
+    /// See [`CommandDispatcher::execute_parsed_async`] for how the result is computed.
+    pub async fn execute_async(
+        &self,
+        command: impl Into<StringReader>,
         source: S,
-    ) -> Result<usize, BrigadierError> {
-        let string_reader: StringReader = command.into();
+    ) -> Result<i32, CommandSyntaxException>
+    where
+        S: Clone + Send + Sync,
+    {
+        self.execute_parsed_async(self.parse(command, source)).await
+    }
+
+    /// The async counterpart of [`CommandDispatcher::execute_parsed`], which runs both sync and
+    /// async commands. Contexts are executed one at a time, in the same order as the sync version.
+    pub async fn execute_parsed_async(
+        &self,
+        parse: ParseResults<'_, S>,
+    ) -> Result<i32, CommandSyntaxException>
+    where
+        S: Clone + Send + Sync,
+    {
+        self.execute_parsed_with(parse, &AsyncExecution).await
+    }
+
+    async fn execute_parsed_with(
+        &self,
+        parse: ParseResults<'_, S>,
+        execution: &impl Execution<S>,
+    ) -> Result<i32, CommandSyntaxException>
+    where
+        S: Clone,
+    {
+        Self::check_parsed(&parse)?;
+
+        let mut result: i32 = 0;
+        let mut successful_forks = 0;
+        let mut forked = false;
+        let mut found_command = false;
+        let command = parse.reader.get_string();
+        let mut contexts = vec![parse.context.build(command)];
+
+        while !contexts.is_empty() {
+            let mut next = Vec::new();
+
+            for context in contexts {
+                if let Some(child) = context.get_child() {
+                    forked |= context.is_forked();
+                    if !child.has_nodes() {
+                        continue;
+                    }
+
+                    found_command = true;
+                    match context.get_redirect_modifier() {
+                        None => next.push(child.copy_for(context.get_source().clone())),
+                        Some(modifier) => match execution.apply(modifier, &context).await {
+                            Ok(sources) => next
+                                .extend(sources.into_iter().map(|source| child.copy_for(source))),
+                            Err(err) => {
+                                self.consumer.on_command_complete(&context, false, 0);
+                                if !forked || Self::requires_async(&err) {
+                                    return Err(err);
+                                }
+                            }
+                        },
+                    }
+                } else if let Some(command) = context.get_command() {
+                    found_command = true;
+                    match execution.run(command, &context).await {
+                        Ok(value) => {
+                            result = result.wrapping_add(value);
+                            self.consumer.on_command_complete(&context, true, value);
+                            successful_forks += 1;
+                        }
+                        Err(err) => {
+                            self.consumer.on_command_complete(&context, false, 0);
+                            if !forked || Self::requires_async(&err) {
+                                return Err(err);
+                            }
+                        }
+                    }
+                }
+            }
+
+            contexts = next;
+        }
+
+        if !found_command {
+            let original = parse.context.build(command);
+            self.consumer.on_command_complete(&original, false, 0);
+            return Err(BrigadierError::DispatcherUnknownCommand.create_with_context(&parse.reader));
+        }
+
+        Ok(if forked { successful_forks } else { result })
+    }
+
+    /// Whether `err` means the dispatcher was called synchronously for a command that needs to
+    /// be awaited, which is a mistake in how it was called rather than a failed branch.
+    fn requires_async(err: &CommandSyntaxException) -> bool {
+        err.get_type() == &BrigadierError::RequiresAsyncExecution
+    }
+
+    /// The error for input that could not be parsed completely, if any.
+    fn check_parsed(parse: &ParseResults<S>) -> Result<(), CommandSyntaxException> {
+        if parse.reader.can_read_char() {
+            return Err(if parse.exceptions.len() == 1 {
+                parse.exceptions[0].1.clone()
+            } else if parse.context.get_range().is_empty() {
+                BrigadierError::DispatcherUnknownCommand.create_with_context(&parse.reader)
+            } else {
+                BrigadierError::DispatcherUnknownArgument.create_with_context(&parse.reader)
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<S: 'static> Default for CommandDispatcher<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How the implementation shared by the sync and async dispatcher methods runs commands,
+/// redirect modifiers and suggestion providers.
+trait Execution<S> {
+    async fn run(
+        &self,
+        command: &Command<S>,
+        context: &CommandContext<'_, S>,
+    ) -> Result<i32, CommandSyntaxException>;
+
+    async fn apply(
+        &self,
+        modifier: &dyn RedirectModifier<S>,
+        context: &CommandContext<'_, S>,
+    ) -> Result<Vec<S>, CommandSyntaxException>;
+
+    async fn list_suggestions(
+        &self,
+        node: &dyn CommandNode<S>,
+        context: &CommandContext<'_, S>,
+        builder: &mut SuggestionsBuilder,
+    ) -> Result<Suggestions, CommandSyntaxException>;
+}
+
+/// Runs everything synchronously, so the shared implementation never has to wait.
+struct SyncExecution;
+
+impl<S> Execution<S> for SyncExecution {
+    async fn run(
+        &self,
+        command: &Command<S>,
+        context: &CommandContext<'_, S>,
+    ) -> Result<i32, CommandSyntaxException> {
+        command.run(context)
+    }
+
+    async fn apply(
+        &self,
+        modifier: &dyn RedirectModifier<S>,
+        context: &CommandContext<'_, S>,
+    ) -> Result<Vec<S>, CommandSyntaxException> {
+        modifier.apply(context)
+    }
+
+    async fn list_suggestions(
+        &self,
+        node: &dyn CommandNode<S>,
+        context: &CommandContext<'_, S>,
+        builder: &mut SuggestionsBuilder,
+    ) -> Result<Suggestions, CommandSyntaxException> {
+        node.list_suggestions(context, builder)
+    }
+}
+
+/// Awaits async commands, redirect modifiers and suggestion providers.
+struct AsyncExecution;
+
+impl<S: Send + Sync> Execution<S> for AsyncExecution {
+    async fn run(
+        &self,
+        command: &Command<S>,
+        context: &CommandContext<'_, S>,
+    ) -> Result<i32, CommandSyntaxException> {
+        command.run_async(context).await
+    }
+
+    async fn apply(
+        &self,
+        modifier: &dyn RedirectModifier<S>,
+        context: &CommandContext<'_, S>,
+    ) -> Result<Vec<S>, CommandSyntaxException> {
+        modifier.apply_async(context).await
+    }
+
+    async fn list_suggestions(
+        &self,
+        node: &dyn CommandNode<S>,
+        context: &CommandContext<'_, S>,
+        builder: &mut SuggestionsBuilder,
+    ) -> Result<Suggestions, CommandSyntaxException> {
+        node.list_suggestions_async(context, builder).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        arguments::{
+            string_argument_type::StringType, ArgumentType, IntegerArgumentType, StringArgumentType,
+        },
+        builder::{literal_argument_builder::literal, required_argument_builder::argument},
+        command::Command,
+        context::string_range::StringRange,
+        exceptions::{DynamicCommandExceptionType, SimpleCommandExceptionType},
+        redirect_modifier::async_modifier,
+        suggestion::suggestion_provider::async_suggestions,
+    };
+
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::{
+            atomic::{AtomicI32, Ordering},
+            Arc, Mutex,
+        },
+        task::{Context as TaskContext, Poll, Waker},
+    };
+
+    use super::*;
+
+    #[test]
+    fn register_command() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        let node = subject.register(literal("foo").executes(Command::new(|_| Ok(42))));
+
+        let node = subject.get_node(node);
+        assert_eq!(node.as_literal().unwrap().get_literal(), "foo");
+        assert!(node.get_command().is_some());
+        assert_eq!(subject.get_node(subject.get_root()).get_children().len(), 1);
+    }
+
+    #[test]
+    fn create_and_merge_commands() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("base").then(literal("foo")));
+        let node = subject
+            .register(literal("base").then(literal("bar").executes(Command::new(|_| Ok(42)))));
+
+        let node = subject.get_node(node);
+        let children = node
+            .get_children()
+            .into_iter()
+            .map(|child| subject.get_node(child).get_name())
+            .collect::<Vec<_>>();
+        assert_eq!(children, vec!["foo", "bar"]);
+        let bar = subject.get_node(node.get_child("bar").unwrap());
+        assert!(bar.get_command().is_some());
+        assert_eq!(subject.get_node(subject.get_root()).get_children().len(), 1);
+    }
+
+    #[test]
+    fn merge_keeps_existing_command() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("base").executes(Command::new(|_| Ok(42))));
+        let node = subject.register(literal("base").then(literal("foo")));
+
+        let node = subject.get_node(node);
+        assert!(node.get_command().is_some());
+        assert!(node.get_child("foo").is_some());
+    }
+
+    #[test]
+    fn merge_grandchildren() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("base").then(literal("foo").then(literal("a"))));
+        let node = subject.register(literal("base").then(literal("foo").then(literal("b"))));
+
+        let foo = subject.get_node(subject.get_node(node).get_child("foo").unwrap());
+        assert!(foo.get_child("a").is_some());
+        assert!(foo.get_child("b").is_some());
+    }
+
+    #[test]
+    fn get_path() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").then(literal("bar").then(literal("baz"))));
+        subject.register(literal("qux"));
+
+        let baz = subject.find_node(&["foo", "bar", "baz"]).unwrap();
+        assert_eq!(subject.get_path(baz), vec!["foo", "bar", "baz"]);
+        let qux = subject.find_node(&["qux"]).unwrap();
+        assert_eq!(subject.get_path(qux), vec!["qux"]);
+    }
+
+    #[test]
+    fn get_path_of_unknown_node() {
+        let subject: CommandDispatcher<()> = CommandDispatcher::new();
+
+        assert!(subject.get_path(NodeId(42)).is_empty());
+    }
+
+    #[test]
+    fn find_node_exists() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").then(literal("bar")));
+
+        let bar = subject.find_node(&["foo", "bar"]).unwrap();
+        assert_eq!(subject.get_node(bar).get_name(), "bar");
+        assert_eq!(subject.find_node(&[]), Some(subject.get_root()));
+    }
+
+    #[test]
+    fn find_node_doesnt_exist() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").then(literal("bar")));
+
+        assert!(subject.find_node(&["foo", "baz"]).is_none());
+        assert!(subject.find_node(&["bar"]).is_none());
+    }
+
+    fn node_names<S>(subject: &CommandDispatcher<S>, parse: &ParseResults<S>) -> Vec<String> {
+        parse
+            .get_context()
+            .get_nodes()
+            .iter()
+            .map(|node| subject.get_node(node.get_node()).get_name().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn parse_full_command() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("foo")
+                .then(literal("bar").executes(Command::new(|_| Ok(42))))
+                .then(literal("baz")),
+        );
+
+        let parse = subject.parse("foo bar", ());
+        assert_eq!(node_names(&subject, &parse), vec!["foo", "bar"]);
+        assert!(parse.get_context().get_command().is_some());
+        assert_eq!(parse.get_context().get_range(), StringRange::between(0, 7));
+        assert!(!parse.get_reader().can_read_char());
+        assert!(parse.get_exceptions().is_empty());
+    }
+
+    #[test]
+    fn parse_stops_at_unknown_child() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").then(literal("bar")));
+
+        let parse = subject.parse("foo baz", ());
+        assert_eq!(node_names(&subject, &parse), vec!["foo"]);
+        assert!(parse.get_context().get_command().is_none());
+        assert_eq!(parse.get_reader().get_cursor(), 4);
+        assert_eq!(parse.get_reader().get_remaining(), "baz");
+    }
+
+    #[test]
+    fn parse_trailing_space() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").then(literal("bar")));
+
+        let parse = subject.parse("foo ", ());
+        assert_eq!(node_names(&subject, &parse), vec!["foo"]);
+        assert_eq!(parse.get_reader().get_remaining(), " ");
+    }
+
+    #[test]
+    fn parse_unknown_command() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo"));
+
+        let parse = subject.parse("bar", ());
+        assert!(node_names(&subject, &parse).is_empty());
+        assert!(parse.get_context().get_command().is_none());
+        assert_eq!(parse.get_reader().get_cursor(), 0);
+    }
+
+    #[test]
+    fn parse_literal_needs_separator() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo"));
+
+        let parse = subject.parse("foobar", ());
+        assert!(node_names(&subject, &parse).is_empty());
+        assert_eq!(parse.get_reader().get_cursor(), 0);
+    }
+
+    #[test]
+    fn parse_skips_impermissible_nodes() {
+        let mut subject: CommandDispatcher<bool> = CommandDispatcher::new();
+        subject.register(literal("foo").requires(Box::new(|allowed: &bool| *allowed)));
+
+        assert!(node_names(&subject, &subject.parse("foo", false)).is_empty());
+        assert_eq!(
+            node_names(&subject, &subject.parse("foo", true)),
+            vec!["foo"]
+        );
+    }
+
+    #[test]
+    fn execute_command() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").executes(Command::new(|_| Ok(42))));
+
+        assert_eq!(subject.execute("foo", ()), Ok(42));
+    }
 
-        // let parsed_result = self.parse(string_reader)?;
+    #[test]
+    fn execute_subcommand() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("foo")
+                .then(literal("a"))
+                .then(literal("=").executes(Command::new(|_| Ok(100))))
+                .then(literal("c"))
+                .executes(Command::new(|_| Ok(42))),
+        );
+
+        assert_eq!(subject.execute("foo =", ()), Ok(100));
+    }
+
+    #[test]
+    fn execute_unknown_command() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("bar"));
+        subject.register(literal("baz"));
+
+        let error = subject.execute("foo", ()).unwrap_err();
+        assert_eq!(error.get_type(), &BrigadierError::DispatcherUnknownCommand);
+        assert_eq!(error.get_cursor(), Some(0));
+    }
+
+    #[test]
+    fn execute_empty_command() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal(""));
+
+        assert_eq!(
+            subject.execute("", ()).unwrap_err().get_type(),
+            &BrigadierError::DispatcherUnknownCommand
+        );
+    }
+
+    #[test]
+    fn execute_impermissible_command() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("foo")
+                .executes(Command::new(|_| Ok(42)))
+                .requires(Box::new(|_| false)),
+        );
+
+        assert_eq!(
+            subject.execute("foo", ()).unwrap_err().get_type(),
+            &BrigadierError::DispatcherUnknownCommand
+        );
+    }
+
+    #[test]
+    fn execute_unknown_subcommand() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").executes(Command::new(|_| Ok(42))));
+
+        let error = subject.execute("foo bar", ()).unwrap_err();
+        assert_eq!(error.get_type(), &BrigadierError::DispatcherUnknownArgument);
+        assert_eq!(error.get_cursor(), Some(4));
+    }
+
+    #[test]
+    fn execute_incorrect_literal() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("foo")
+                .executes(Command::new(|_| Ok(42)))
+                .then(literal("bar")),
+        );
+
+        let error = subject.execute("foo baz", ()).unwrap_err();
+        assert_eq!(error.get_type(), &BrigadierError::DispatcherUnknownArgument);
+        assert_eq!(error.get_cursor(), Some(4));
+    }
+
+    #[test]
+    fn execute_argument_error_context() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("give").then(
+                argument("player", StringArgumentType::new(StringType::Word)).then(
+                    argument("amount", IntegerArgumentType::new().with_range(1, 64))
+                        .executes(Command::new(|_| Ok(1))),
+                ),
+            ),
+        );
+
+        let error = subject.execute("give Steve 640", ()).unwrap_err();
+        assert_eq!(
+            error.get_type(),
+            &BrigadierError::IntegerTooHigh {
+                result: 640,
+                max: 64
+            }
+        );
+        assert_eq!(error.get_input(), Some("give Steve 640"));
+        assert_eq!(error.get_cursor(), Some(11));
+        assert_eq!(
+            error.get_context(),
+            Some("...ive Steve <--[HERE]".to_owned())
+        );
+    }
+
+    #[test]
+    fn execute_multibyte_arguments() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("msg").then(
+            argument("player", StringArgumentType::new(StringType::Word)).then(
+                argument("message", StringArgumentType::new(StringType::Greedy)).executes(
+                    Command::new(|c| {
+                        let player = c.get_argument::<String>("player")?;
+                        let message = c.get_argument::<String>("message")?;
+                        assert_eq!(player, "Jürgen");
+                        assert_eq!(message, "grüße 😀 an alle");
+                        Ok(message.chars().count() as i32)
+                    }),
+                ),
+            ),
+        ));
+
+        assert_eq!(subject.execute("msg Jürgen grüße 😀 an alle", ()), Ok(15));
+    }
+
+    #[test]
+    fn execute_extended_numbers() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("color").then(
+                argument("rgb", IntegerArgumentType::new())
+                    .executes(Command::new(|c| Ok(*c.get_argument::<i32>("rgb")?))),
+            ),
+        );
+
+        let reader = StringReader::new("color 0xFF_00FF").with_extended_numbers(true);
+        assert_eq!(subject.execute(reader, ()), Ok(0xFF00FF));
+        assert!(subject.execute("color 0xFF_00FF", ()).is_err());
+    }
+
+    #[test]
+    fn execute_with_syntax() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("tp").then(
+            argument("dimension", StringArgumentType::new(StringType::Word)).executes(
+                Command::new(|c| {
+                    assert_eq!(
+                        c.get_argument::<String>("dimension")?,
+                        "minecraft:overworld"
+                    );
+                    Ok(1)
+                }),
+            ),
+        ));
+
+        assert!(subject.execute("tp minecraft:overworld", ()).is_err());
+
+        subject.set_syntax(ReaderSyntax::new().allow_unquoted([':']));
+        assert_eq!(subject.execute("tp minecraft:overworld", ()), Ok(1));
+    }
+
+    #[test]
+    fn execute_with_syntax_keeps_reader_modes() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("c").then(
+                argument("value", IntegerArgumentType::new())
+                    .executes(Command::new(|c| Ok(*c.get_argument::<i32>("value")?))),
+            ),
+        );
+        subject.set_syntax(ReaderSyntax::new().allow_unquoted([':']));
+
+        assert_eq!(
+            subject.execute(StringReader::new("c 0x10").with_extended_numbers(true), ()),
+            Ok(16)
+        );
+        assert!(subject.execute("c 0x10", ()).is_err());
+    }
+
+    #[test]
+    fn execute_orphaned_subcommand() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").then(literal("bar")));
+
+        assert_eq!(
+            subject.execute("foo", ()).unwrap_err().get_type(),
+            &BrigadierError::DispatcherUnknownCommand
+        );
+    }
+
+    #[test]
+    fn execute_command_with_captured_state() {
+        let calls = Arc::new(AtomicI32::new(0));
+        let counter = calls.clone();
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").executes(Command::new(move |_| {
+            Ok(counter.fetch_add(1, Ordering::SeqCst) + 1)
+        })));
+
+        assert_eq!(subject.execute("foo", ()), Ok(1));
+        assert_eq!(subject.execute("foo", ()), Ok(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn execute_command_error() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("buy").executes(Command::new(|c| {
+            let not_enough = SimpleCommandExceptionType::new("Not enough money");
+            if *c.get_source() < 10 {
+                return Err(not_enough.create());
+            }
+            Ok(1)
+        })));
+
+        assert_eq!(subject.execute("buy", 20), Ok(1));
+        assert_eq!(
+            subject.execute("buy", 5).unwrap_err().get_type(),
+            &BrigadierError::Custom("Not enough money".to_owned())
+        );
+    }
+
+    #[test]
+    fn execute_argument() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("foo").then(
+                argument("bar", IntegerArgumentType::new())
+                    .executes(Command::new(|c| Ok(*c.get_argument::<i32>("bar")?))),
+            ),
+        );
+
+        assert_eq!(subject.execute("foo 123", ()), Ok(123));
+        assert!(subject.execute("foo bar", ()).is_err());
+    }
+
+    #[test]
+    fn get_argument_errors() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").then(argument("bar", IntegerArgumentType::new())));
+
+        let parse = subject.parse("foo 123", ());
+        let context = parse.get_context().build("foo 123");
+        assert_eq!(context.get_argument::<i32>("bar"), Ok(&123));
+        assert_eq!(
+            context.get_argument::<i32>("baz"),
+            Err(BrigadierError::NoSuchArgument("baz".to_owned()))
+        );
+        assert_eq!(
+            context.get_argument::<String>("bar"),
+            Err(BrigadierError::ArgumentTypeMismatch {
+                name: "bar".to_owned(),
+                expected: std::any::type_name::<String>(),
+            })
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Direction {
+        North,
+        South,
+    }
+
+    struct DirectionArgumentType;
+
+    impl<S> ArgumentType<S> for DirectionArgumentType {
+        type Type = Direction;
+
+        fn parse(&self, reader: &mut StringReader) -> Result<Direction, CommandSyntaxException> {
+            let start = reader.get_cursor();
+            match reader.read_unqoted_string()?.as_str() {
+                "north" => Ok(Direction::North),
+                "south" => Ok(Direction::South),
+                word => {
+                    reader.set_cursor(start);
+                    let unknown = DynamicCommandExceptionType::new(|direction: &str| {
+                        format!("Unknown direction {}", direction)
+                    });
+                    Err(unknown.create_with_context(reader, word))
+                }
+            }
+        }
+    }
+
+    impl std::fmt::Display for DirectionArgumentType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "direction()")
+        }
+    }
+
+    #[test]
+    fn execute_mixed_argument_types() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("go")
+                .then(argument("steps", IntegerArgumentType::new()).then(
+                    argument("name", StringArgumentType::new(StringType::Word)).executes(
+                        Command::new(|c| {
+                            let steps = *c.get_argument::<i32>("steps")?;
+                            let name = c.get_argument::<String>("name")?;
+                            Ok(steps * name.len() as i32)
+                        }),
+                    ),
+                ))
+                .then(
+                    argument("direction", DirectionArgumentType).executes(Command::new(|c| {
+                        match c.get_argument::<Direction>("direction")? {
+                            Direction::North => Ok(1),
+                            Direction::South => Ok(-1),
+                        }
+                    })),
+                ),
+        );
+
+        assert_eq!(subject.execute("go 3 abcd", ()), Ok(12));
+        assert_eq!(subject.execute("go north", ()), Ok(1));
+        assert_eq!(subject.execute("go south", ()), Ok(-1));
+        assert!(subject.execute("go east", ()).is_err());
+
+        let parse = subject.parse("go east", ());
+        assert!(parse.get_exceptions().iter().any(|(_, error)| {
+            *error.get_type() == BrigadierError::Custom("Unknown direction east".to_owned())
+        }));
+    }
+
+    #[test]
+    fn execute_prefers_literal_over_argument() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("foo")
+                .then(literal("1").executes(Command::new(|_| Ok(1))))
+                .then(
+                    argument("bar", IntegerArgumentType::new()).executes(Command::new(|_| Ok(2))),
+                ),
+        );
+
+        assert_eq!(subject.execute("foo 1", ()), Ok(1));
+        assert_eq!(subject.execute("foo 2", ()), Ok(2));
+    }
+
+    #[test]
+    fn execute_redirected() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("actual").executes(Command::new(|c| Ok(*c.get_source()))));
+        subject.register(literal("redirected").redirect(subject.get_root()));
+
+        let parse = subject.parse("redirected actual", 5);
+        let child = parse.get_context().get_child().unwrap();
+        assert_eq!(child.get_range(), StringRange::between(11, 17));
+        assert_eq!(child.get_root_node(), subject.get_root());
+        let actual = subject.get_node(child.get_nodes()[0].get_node());
+        assert_eq!(actual.get_name(), "actual");
+
+        assert_eq!(subject.execute_parsed(parse), Ok(5));
+    }
+
+    #[test]
+    fn execute_redirected_multiple_times() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("actual").executes(Command::new(|c| Ok(*c.get_source()))));
+        subject.register(literal("redirected").redirect(subject.get_root()));
+
+        let parse = subject.parse("redirected redirected actual", 5);
+        let mut context = parse.get_context();
+        for _ in 0..2 {
+            assert_eq!(context.get_nodes().len(), 1);
+            context = context.get_child().unwrap();
+        }
+        assert_eq!(context.get_range(), StringRange::between(22, 28));
+        assert!(context.get_child().is_none());
+        assert!(std::ptr::eq(context, parse.get_context().get_last_child()));
+
+        let built = parse.get_context().build(parse.get_reader().get_string());
+        let last = built.get_last_child();
+        assert_eq!(last.get_range(), StringRange::between(22, 28));
+        assert_eq!(last.get_root_node(), subject.get_root());
+        let actual = subject.get_node(last.get_nodes()[0].get_node());
+        assert_eq!(actual.get_name(), "actual");
+
+        assert_eq!(subject.execute_parsed(parse), Ok(5));
+    }
+
+    #[test]
+    fn execute_redirected_to_sibling() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        let target = subject.register(
+            literal("target")
+                .then(literal("actual").executes(Command::new(|c| Ok(*c.get_source())))),
+        );
+        subject.register(literal("redirected").redirect(target));
+
+        let parse = subject.parse("redirected actual", 5);
+        let context = parse.get_context().build("redirected actual");
+        assert_eq!(context.get_root_node(), subject.get_root());
+        assert_eq!(context.get_last_child().get_root_node(), target);
+
+        assert_eq!(subject.execute("redirected actual", 5), Ok(5));
+        assert_eq!(
+            subject
+                .execute("redirected target", 5)
+                .unwrap_err()
+                .get_type(),
+            &BrigadierError::DispatcherUnknownArgument
+        );
+    }
+
+    #[test]
+    fn execute_redirected_with_modifier() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("actual").executes(Command::new(|c| Ok(*c.get_source()))));
+        subject.register(
+            literal("redirected").redirect_with(subject.get_root(), |c: &CommandContext<i32>| {
+                Ok(*c.get_source() * 10)
+            }),
+        );
+
+        assert_eq!(subject.execute("redirected actual", 5), Ok(50));
+    }
+
+    #[test]
+    fn execute_redirected_results_wrap() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("actual").executes(Command::new(|c| Ok(*c.get_source()))));
+        subject.register(literal("redirected").forward(
+            subject.get_root(),
+            Some(Box::new(|_: &CommandContext<i32>| {
+                Ok(vec![i32::MAX, i32::MAX - 1])
+            })),
+            false,
+        ));
+
+        // like Java, the sum overflows silently instead of panicking
+        assert_eq!(
+            subject.execute("redirected actual", 0),
+            Ok(i32::MAX.wrapping_add(i32::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn execute_forked() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("actual").executes(Command::new(|c| Ok(*c.get_source()))));
+        subject.register(
+            literal("redirected").fork(subject.get_root(), |_: &CommandContext<i32>| {
+                Ok(vec![1, 2, 3])
+            }),
+        );
+
+        // forked executions count successful branches rather than summing results
+        assert_eq!(subject.execute("redirected actual", 0), Ok(3));
+    }
+
+    #[test]
+    fn execute_forked_swallows_errors() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("actual").executes(Command::new(|c| {
+            let source = *c.get_source();
+            if source < 0 {
+                Err(BrigadierError::IntegerTooLow {
+                    result: source,
+                    min: 0,
+                }
+                .create())
+            } else {
+                Ok(source)
+            }
+        })));
+        subject.register(
+            literal("redirected").fork(subject.get_root(), |_: &CommandContext<i32>| {
+                Ok(vec![1, -1, 2])
+            }),
+        );
+
+        assert_eq!(subject.execute("redirected actual", 0), Ok(2));
+    }
+
+    #[test]
+    fn execute_redirect_modifier_error() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("actual").executes(Command::new(|c| Ok(*c.get_source()))));
+        subject.register(
+            literal("redirected").redirect_with(subject.get_root(), |_: &CommandContext<i32>| {
+                Err(BrigadierError::InvalidInt("foo".to_owned()).create())
+            }),
+        );
+
+        assert_eq!(
+            subject
+                .execute("redirected actual", 5)
+                .unwrap_err()
+                .get_type(),
+            &BrigadierError::InvalidInt("foo".to_owned())
+        );
+    }
+
+    #[test]
+    fn execute_forked_modifier_error_is_swallowed() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("actual").executes(Command::new(|c| Ok(*c.get_source()))));
+        subject.register(
+            literal("redirected").fork(subject.get_root(), |_: &CommandContext<i32>| {
+                Err(BrigadierError::InvalidInt("foo".to_owned()).create())
+            }),
+        );
+
+        assert_eq!(subject.execute("redirected actual", 5), Ok(0));
+    }
+
+    fn recording_consumer(
+        subject: &mut CommandDispatcher<i32>,
+    ) -> Arc<Mutex<Vec<(i32, bool, i32)>>> {
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let recorded = results.clone();
+        subject.set_consumer(move |c: &CommandContext<i32>, success, result| {
+            recorded
+                .lock()
+                .unwrap()
+                .push((*c.get_source(), success, result))
+        });
+        results
+    }
+
+    #[test]
+    fn consumer_sees_command_result() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("foo").executes(Command::new(|_| Ok(42))));
+        let results = recording_consumer(&mut subject);
+
+        assert_eq!(subject.execute("foo", 7), Ok(42));
+        assert_eq!(*results.lock().unwrap(), vec![(7, true, 42)]);
+    }
+
+    #[test]
+    fn consumer_sees_command_failure() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("foo").executes(Command::new(|_| {
+            Err(BrigadierError::InvalidInt("foo".to_owned()).create())
+        })));
+        let results = recording_consumer(&mut subject);
+
+        assert_eq!(
+            subject.execute("foo", 7).unwrap_err().get_type(),
+            &BrigadierError::InvalidInt("foo".to_owned())
+        );
+        assert_eq!(*results.lock().unwrap(), vec![(7, false, 0)]);
+    }
+
+    #[test]
+    fn consumer_sees_missing_command() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("foo").then(literal("bar")));
+        let results = recording_consumer(&mut subject);
+
+        assert_eq!(
+            subject.execute("foo", 7).unwrap_err().get_type(),
+            &BrigadierError::DispatcherUnknownCommand
+        );
+        assert_eq!(*results.lock().unwrap(), vec![(7, false, 0)]);
+    }
+
+    #[test]
+    fn consumer_sees_every_fork() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(
+            literal("actual").executes(Command::new(|c| match *c.get_source() {
+                source if source < 0 => Err(BrigadierError::InvalidInt("foo".to_owned()).create()),
+                source => Ok(source * 10),
+            })),
+        );
+        subject.register(
+            literal("redirected").fork(subject.get_root(), |_: &CommandContext<i32>| {
+                Ok(vec![1, -1, 2])
+            }),
+        );
+        let results = recording_consumer(&mut subject);
+
+        assert_eq!(subject.execute("redirected actual", 0), Ok(2));
+        assert_eq!(
+            *results.lock().unwrap(),
+            vec![(1, true, 10), (-1, false, 0), (2, true, 20)]
+        );
+    }
+
+    #[test]
+    fn consumer_sees_redirect_modifier_failure() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("actual").executes(Command::new(|c| Ok(*c.get_source()))));
+        subject.register(
+            literal("redirected").fork(subject.get_root(), |_: &CommandContext<i32>| {
+                Err(BrigadierError::InvalidInt("foo".to_owned()).create())
+            }),
+        );
+        let results = recording_consumer(&mut subject);
+
+        assert_eq!(subject.execute("redirected actual", 3), Ok(0));
+        assert_eq!(*results.lock().unwrap(), vec![(3, false, 0)]);
+    }
+
+    fn usage_subject() -> CommandDispatcher<()> {
+        let command = || Command::new(|_| Ok(0));
+        let mut subject = CommandDispatcher::new();
+        subject.register(
+            literal("a")
+                .then(
+                    literal("1")
+                        .then(literal("i").executes(command()))
+                        .then(literal("ii").executes(command())),
+                )
+                .then(
+                    literal("2")
+                        .then(literal("i").executes(command()))
+                        .then(literal("ii").executes(command())),
+                ),
+        );
+        subject.register(literal("b").then(literal("1").executes(command())));
+        subject.register(literal("c").executes(command()));
+        subject.register(
+            literal("d")
+                .requires(Box::new(|_| false))
+                .executes(command()),
+        );
+        subject.register(
+            literal("e").executes(command()).then(
+                literal("1")
+                    .executes(command())
+                    .then(literal("i").executes(command()))
+                    .then(literal("ii").executes(command())),
+            ),
+        );
+        subject.register(
+            literal("f")
+                .then(
+                    literal("1").then(literal("i").executes(command())).then(
+                        literal("ii")
+                            .executes(command())
+                            .requires(Box::new(|_| false)),
+                    ),
+                )
+                .then(
+                    literal("2")
+                        .then(
+                            literal("i")
+                                .executes(command())
+                                .requires(Box::new(|_| false)),
+                        )
+                        .then(literal("ii").executes(command())),
+                ),
+        );
+        subject.register(
+            literal("g")
+                .executes(command())
+                .then(literal("1").then(literal("i").executes(command()))),
+        );
+        subject.register(
+            literal("h")
+                .executes(command())
+                .then(literal("1").then(literal("i").executes(command())))
+                .then(literal("2").then(literal("i").then(literal("ii").executes(command()))))
+                .then(literal("3").executes(command())),
+        );
+        subject.register(
+            literal("i")
+                .executes(command())
+                .then(literal("1").executes(command()))
+                .then(literal("2").executes(command())),
+        );
+        let h = subject.find_node(&["h"]).unwrap();
+        subject.register(literal("k").redirect(h));
+        subject
+    }
+
+    fn smart_usage(subject: &CommandDispatcher<()>, node: NodeId) -> Vec<(String, String)> {
+        subject
+            .get_smart_usage(node, &())
+            .into_iter()
+            .map(|(node, usage)| (subject.get_node(node).get_name().to_owned(), usage))
+            .collect()
+    }
+
+    fn pairs(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+        expected
+            .iter()
+            .map(|(node, usage)| (node.to_string(), usage.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn all_usage_no_commands() {
+        let subject: CommandDispatcher<()> = CommandDispatcher::new();
+        assert!(subject
+            .get_all_usage(subject.get_root(), &(), true)
+            .is_empty());
+    }
+
+    #[test]
+    fn smart_usage_no_commands() {
+        let subject: CommandDispatcher<()> = CommandDispatcher::new();
+        assert!(subject.get_smart_usage(subject.get_root(), &()).is_empty());
+    }
+
+    #[test]
+    fn all_usage_root() {
+        let subject = usage_subject();
+        assert_eq!(
+            subject.get_all_usage(subject.get_root(), &(), true),
+            vec![
+                "a 1 i", "a 1 ii", "a 2 i", "a 2 ii", "b 1", "c", "e", "e 1", "e 1 i", "e 1 ii",
+                "f 1 i", "f 2 ii", "g", "g 1 i", "h", "h 1 i", "h 2 i ii", "h 3", "i", "i 1",
+                "i 2", "k -> h",
+            ]
+        );
+    }
+
+    #[test]
+    fn all_usage_unrestricted() {
+        let subject = usage_subject();
+        let usage = subject.get_all_usage(subject.get_root(), &(), false);
+        assert!(usage.contains(&"d".to_owned()));
+        assert!(usage.contains(&"f 1 ii".to_owned()));
+        assert!(usage.contains(&"f 2 i".to_owned()));
+    }
+
+    #[test]
+    fn smart_usage_root() {
+        let subject = usage_subject();
+        assert_eq!(
+            smart_usage(&subject, subject.get_root()),
+            pairs(&[
+                ("a", "a (1|2)"),
+                ("b", "b 1"),
+                ("c", "c"),
+                ("e", "e [1]"),
+                ("f", "f (1|2)"),
+                ("g", "g [1]"),
+                ("h", "h [1|2|3]"),
+                ("i", "i [1|2]"),
+                ("k", "k -> h"),
+            ])
+        );
+    }
+
+    #[test]
+    fn smart_usage_h() {
+        let subject = usage_subject();
+        let h = subject.find_node(&["h"]).unwrap();
+        assert_eq!(
+            smart_usage(&subject, h),
+            pairs(&[("1", "[1] i"), ("2", "[2] i ii"), ("3", "[3]")])
+        );
+    }
+
+    #[test]
+    fn usage_redirect_to_root() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("execute").redirect(subject.get_root()));
+
+        assert_eq!(
+            subject.get_all_usage(subject.get_root(), &(), true),
+            vec!["execute ..."]
+        );
+        assert_eq!(
+            smart_usage(&subject, subject.get_root()),
+            pairs(&[("execute", "execute ...")])
+        );
+    }
+
+    #[test]
+    fn usage_argument() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("foo").then(
+                argument("bar", IntegerArgumentType::new()).executes(Command::new(|_| Ok(0))),
+            ),
+        );
+
+        assert_eq!(
+            subject.get_all_usage(subject.get_root(), &(), true),
+            vec!["foo <bar>"]
+        );
+        assert_eq!(
+            smart_usage(&subject, subject.get_root()),
+            pairs(&[("foo", "foo <bar>")])
+        );
+    }
+
+    #[test]
+    fn parse_results_outlive_input() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("foo")
+                .then(literal("bar").executes(Command::new(|_| Ok(42))))
+                .then(literal("baz")),
+        );
+
+        let input = String::from("foo ba");
+        let parse = subject.parse(input, ());
+
+        let first = subject.get_completion_suggestions(&parse, 6).unwrap();
+        let second = subject.get_completion_suggestions(&parse, 6).unwrap();
+        assert_eq!(suggestion_texts(&first), vec!["bar", "baz"]);
+        assert_eq!(suggestion_texts(&first), suggestion_texts(&second));
+    }
+
+    #[test]
+    fn parse_results_sent_to_other_thread() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("foo").executes(Command::new(|c| Ok(*c.get_source()))));
+
+        let subject = &subject;
+        let parse = subject.parse(String::from("foo"), 7);
+        let result = std::thread::scope(|scope| {
+            scope
+                .spawn(move || subject.execute_parsed(parse))
+                .join()
+                .unwrap()
+        });
+        assert_eq!(result, Ok(7));
+    }
+
+    fn suggestion_texts(suggestions: &Suggestions) -> Vec<&str> {
+        suggestions
+            .get_list()
+            .iter()
+            .map(|suggestion| suggestion.get_text())
+            .collect()
+    }
+
+    fn test_suggestions(
+        subject: &CommandDispatcher<()>,
+        contents: &str,
+        cursor: usize,
+        range: StringRange,
+        expected: &[&str],
+    ) {
+        let parse = subject.parse(contents, ());
+        let result = subject.get_completion_suggestions(&parse, cursor).unwrap();
+        assert_eq!(result.get_range(), &range, "cursor {}", cursor);
+        assert_eq!(suggestion_texts(&result), expected, "cursor {}", cursor);
+    }
+
+    #[test]
+    fn completion_suggestions_root_commands() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo"));
+        subject.register(literal("bar"));
+        subject.register(literal("baz"));
+
+        test_suggestions(&subject, "", 0, StringRange::at(0), &["bar", "baz", "foo"]);
+    }
+
+    #[test]
+    fn completion_suggestions_root_commands_with_input_offset() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo"));
+        subject.register(literal("bar"));
+        subject.register(literal("baz"));
+
+        let mut reader = StringReader::new("OOO");
+        reader.set_cursor(3);
+        let parse = subject.parse(reader, ());
+        let result = subject.get_completion_suggestions(&parse, 3).unwrap();
+        assert_eq!(result.get_range(), &StringRange::at(3));
+        assert_eq!(suggestion_texts(&result), vec!["bar", "baz", "foo"]);
+    }
+
+    #[test]
+    fn completion_suggestions_cursor_out_of_range() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo"));
+
+        let mut reader = StringReader::new("OOOf");
+        reader.set_cursor(3);
+        let parse = subject.parse(reader, ());
+        assert!(subject
+            .get_completion_suggestions(&parse, 1)
+            .unwrap()
+            .is_empty());
+
+        let parse = subject.parse("f", ());
+        let result = subject.get_completion_suggestions(&parse, 10).unwrap();
+        assert_eq!(result.get_range(), &StringRange::between(0, 1));
+        assert_eq!(suggestion_texts(&result), vec!["foo"]);
+    }
+
+    #[test]
+    fn completion_suggestions_root_commands_partial() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo"));
+        subject.register(literal("bar"));
+        subject.register(literal("baz"));
+
+        test_suggestions(
+            &subject,
+            "b",
+            1,
+            StringRange::between(0, 1),
+            &["bar", "baz"],
+        );
+    }
+
+    #[test]
+    fn completion_suggestions_sub_commands() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("parent")
+                .then(literal("foo"))
+                .then(literal("bar"))
+                .then(literal("baz")),
+        );
+
+        test_suggestions(
+            &subject,
+            "parent ",
+            7,
+            StringRange::at(7),
+            &["bar", "baz", "foo"],
+        );
+        test_suggestions(
+            &subject,
+            "parent b",
+            8,
+            StringRange::between(7, 8),
+            &["bar", "baz"],
+        );
+    }
+
+    #[test]
+    fn completion_suggestions_moving_cursor_sub_commands() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("parent_one")
+                .then(literal("faz"))
+                .then(literal("fbz"))
+                .then(literal("gaz")),
+        );
+        subject.register(literal("parent_two"));
+
+        let input = "parent_one faz ";
+        test_suggestions(
+            &subject,
+            input,
+            0,
+            StringRange::at(0),
+            &["parent_one", "parent_two"],
+        );
+        test_suggestions(
+            &subject,
+            input,
+            1,
+            StringRange::between(0, 1),
+            &["parent_one", "parent_two"],
+        );
+        test_suggestions(
+            &subject,
+            input,
+            7,
+            StringRange::between(0, 7),
+            &["parent_one", "parent_two"],
+        );
+        test_suggestions(
+            &subject,
+            input,
+            8,
+            StringRange::between(0, 8),
+            &["parent_one"],
+        );
+        test_suggestions(&subject, input, 10, StringRange::at(0), &[]);
+        test_suggestions(
+            &subject,
+            input,
+            11,
+            StringRange::at(11),
+            &["faz", "fbz", "gaz"],
+        );
+        test_suggestions(
+            &subject,
+            input,
+            12,
+            StringRange::between(11, 12),
+            &["faz", "fbz"],
+        );
+        test_suggestions(&subject, input, 13, StringRange::between(11, 13), &["faz"]);
+        test_suggestions(&subject, input, 14, StringRange::at(0), &[]);
+        test_suggestions(&subject, input, 15, StringRange::at(0), &[]);
+    }
+
+    #[test]
+    fn completion_suggestions_custom_provider() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").then(
+            argument("bar", IntegerArgumentType::new()).suggests(
+                |_: &CommandContext<()>, builder: &mut SuggestionsBuilder| {
+                    Ok(builder.suggest("1").suggest("12").suggest("2").build())
+                },
+            ),
+        ));
+
+        test_suggestions(&subject, "foo ", 4, StringRange::at(4), &["1", "12", "2"]);
+    }
+
+    #[test]
+    fn completion_suggestions_redirect() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        let actual = subject.register(literal("actual").then(literal("sub")));
+        subject.register(literal("redirect").redirect(actual));
+
+        test_suggestions(&subject, "redirect ", 9, StringRange::at(9), &["sub"]);
+        test_suggestions(
+            &subject,
+            "redirect s",
+            10,
+            StringRange::between(9, 10),
+            &["sub"],
+        );
+    }
+
+    #[test]
+    fn completion_suggestions_moving_cursor_redirect() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        let actual_one = subject.register(
+            literal("actual_one")
+                .then(literal("faz"))
+                .then(literal("fbz"))
+                .then(literal("gaz")),
+        );
+        subject.register(literal("actual_two"));
+        subject.register(literal("redirect_one").redirect(actual_one));
+        subject.register(literal("redirect_two").redirect(actual_one));
+
+        let input = "redirect_one faz ";
+        test_suggestions(
+            &subject,
+            input,
+            0,
+            StringRange::at(0),
+            &["actual_one", "actual_two", "redirect_one", "redirect_two"],
+        );
+        test_suggestions(
+            &subject,
+            input,
+            9,
+            StringRange::between(0, 9),
+            &["redirect_one", "redirect_two"],
+        );
+        test_suggestions(
+            &subject,
+            input,
+            10,
+            StringRange::between(0, 10),
+            &["redirect_one"],
+        );
+        test_suggestions(&subject, input, 12, StringRange::at(0), &[]);
+        test_suggestions(
+            &subject,
+            input,
+            13,
+            StringRange::at(13),
+            &["faz", "fbz", "gaz"],
+        );
+        test_suggestions(
+            &subject,
+            input,
+            14,
+            StringRange::between(13, 14),
+            &["faz", "fbz"],
+        );
+        test_suggestions(&subject, input, 15, StringRange::between(13, 15), &["faz"]);
+        test_suggestions(&subject, input, 16, StringRange::at(0), &[]);
+        test_suggestions(&subject, input, 17, StringRange::at(0), &[]);
+    }
+
+    #[test]
+    fn find_ambiguities_none() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").then(literal("bar")).then(literal("baz")));
+        subject.register(literal("bar").then(literal("foo")));
+
+        let mut found = Vec::new();
+        subject.find_ambiguities(&mut |_: &dyn CommandNode<()>,
+                                       child: &dyn CommandNode<()>,
+                                       _: &dyn CommandNode<()>,
+                                       _: &[String]| {
+            found.push(child.get_name().to_owned())
+        });
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn find_ambiguities_literal_and_argument() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("foo")
+                .then(literal("1"))
+                .then(argument("bar", IntegerArgumentType::new())),
+        );
+
+        let mut found = Vec::new();
+        subject.find_ambiguities(&mut |parent: &dyn CommandNode<()>,
+                                       child: &dyn CommandNode<()>,
+                                       sibling: &dyn CommandNode<()>,
+                                       inputs: &[String]| {
+            found.push((
+                parent.get_name().to_owned(),
+                child.get_name().to_owned(),
+                sibling.get_name().to_owned(),
+                inputs.to_vec(),
+            ))
+        });
+        assert_eq!(
+            found,
+            vec![(
+                "foo".to_owned(),
+                "1".to_owned(),
+                "bar".to_owned(),
+                vec!["1".to_owned()]
+            )]
+        );
+    }
+
+    #[test]
+    fn find_ambiguities_with_syntax() {
+        fn ambiguities(dispatcher: &CommandDispatcher<()>) -> Vec<(String, String)> {
+            let mut found = Vec::new();
+            dispatcher.find_ambiguities(
+                &mut |_: &dyn CommandNode<()>,
+                      child: &dyn CommandNode<()>,
+                      sibling: &dyn CommandNode<()>,
+                      _: &[String]| {
+                    found.push((child.get_name().to_owned(), sibling.get_name().to_owned()))
+                },
+            );
+            found
+        }
+
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(
+            literal("give")
+                .then(literal("minecraft:stone"))
+                .then(argument("item", StringArgumentType::new(StringType::Word))),
+        );
+        assert!(ambiguities(&subject).is_empty());
+
+        subject.set_syntax(ReaderSyntax::new().allow_unquoted([':']));
+        assert_eq!(
+            ambiguities(&subject),
+            vec![("minecraft:stone".to_owned(), "item".to_owned())]
+        );
+    }
+
+    /// Polls `future` to completion; everything awaited in these tests wakes immediately.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut context = TaskContext::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    /// A future that is pending once before completing, like real I/O would be.
+    async fn yield_now() {
+        let mut yielded = false;
+        std::future::poll_fn(|context| {
+            if yielded {
+                Poll::Ready(())
+            } else {
+                yielded = true;
+                context.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    #[test]
+    fn execute_async_command() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("sync").executes(Command::new(|c| Ok(*c.get_source()))));
+        subject.register(literal("async").executes(Command::new_async(|c| {
+            Box::pin(async move {
+                yield_now().await;
+                Ok(*c.get_source() * 2)
+            })
+        })));
+
+        assert_eq!(block_on(subject.execute_async("sync", 5)), Ok(5));
+        assert_eq!(block_on(subject.execute_async("async", 5)), Ok(10));
+        assert_eq!(
+            block_on(subject.execute_async("unknown", 5))
+                .unwrap_err()
+                .get_type(),
+            &BrigadierError::DispatcherUnknownCommand
+        );
+    }
+
+    #[test]
+    fn execute_async_command_synchronously() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject
+            .register(literal("async").executes(Command::new_async(|_| Box::pin(async { Ok(1) }))));
+
+        assert_eq!(
+            subject.execute("async", 0).unwrap_err().get_type(),
+            &BrigadierError::RequiresAsyncExecution
+        );
+    }
+
+    #[test]
+    fn execute_async_forked_with_async_modifier() {
+        let mut subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        subject.register(literal("actual").executes(Command::new_async(|c| {
+            Box::pin(async move { Ok(*c.get_source()) })
+        })));
+        subject.register(literal("redirected").fork(
+            subject.get_root(),
+            async_modifier(|_: &CommandContext<i32>| {
+                Box::pin(async {
+                    yield_now().await;
+                    Ok(vec![1, 2, 3])
+                })
+            }),
+        ));
+
+        assert_eq!(
+            block_on(subject.execute_async("redirected actual", 0)),
+            Ok(3)
+        );
+        assert_eq!(
+            subject
+                .execute("redirected actual", 0)
+                .unwrap_err()
+                .get_type(),
+            &BrigadierError::RequiresAsyncExecution
+        );
+    }
+
+    #[test]
+    fn completion_suggestions_async_provider() {
+        let mut subject: CommandDispatcher<()> = CommandDispatcher::new();
+        subject.register(literal("foo").then(
+            argument("bar", IntegerArgumentType::new()).suggests(async_suggestions(
+                |_: &CommandContext<()>, builder: &mut SuggestionsBuilder| {
+                    Box::pin(async move {
+                        yield_now().await;
+                        Ok(builder.suggest("1").suggest("2").build())
+                    })
+                },
+            )),
+        ));
+
+        let parse = subject.parse("foo ", ());
+        let result = block_on(subject.get_completion_suggestions_async(&parse, 4)).unwrap();
+        assert_eq!(suggestion_texts(&result), vec!["1", "2"]);
+        assert_eq!(
+            subject
+                .get_completion_suggestions(&parse, 4)
+                .unwrap_err()
+                .get_type(),
+            &BrigadierError::RequiresAsyncExecution
+        );
+    }
 
-        println!("{:?}", string_reader.get_string());
+    fn assert_send<T: Send>(_: T) {}
 
-        Ok(0)
+    #[test]
+    fn async_futures_are_send() {
+        let subject: CommandDispatcher<i32> = CommandDispatcher::new();
+        assert_send(subject.execute_async("foo", 0));
+        assert_send(subject.execute_parsed_async(subject.parse("foo", 0)));
+        let parse = subject.parse("foo", 0);
+        assert_send(subject.get_completion_suggestions_async(&parse, 3));
     }
 }
@@ -1,33 +1,130 @@
 use std::{any::Any, collections::HashMap};
 
 use crate::{
-    arguments::{
-        BoolArgumentType, DoubleArgumentType, FloatArgumentType, IntegerArgumentType,
-        LongArgumentType, StringArgumentType,
-    },
-    command::Command,
+    command::Command, error::BrigadierError, redirect_modifier::RedirectModifier,
+    tree::node_id::NodeId,
 };
 
-use super::{parsed_argument::ParsedArgument, string_range::StringRange};
-
-pub enum ArgumentType {
-    Bool(BoolArgumentType),
-    Double(DoubleArgumentType),
-    Float(FloatArgumentType),
-    Integer(IntegerArgumentType),
-    Long(LongArgumentType),
-    String(StringArgumentType),
-}
+use super::{
+    parsed_argument::ErasedArgument, parsed_command_node::ParsedCommandNode,
+    string_range::StringRange,
+};
 
-pub struct CommandContext<S> {
+#[derive(Clone)]
+pub struct CommandContext<'a, S> {
     source: S,
     input: String,
-    command: Box<Command<S>>,
-    arguments: HashMap<String, ParsedArgument<S, ArgumentType>>,
-    // root_node: CommandNode<S>,
-    // nodes: Vec<ParsedCommandNode<S>>,
+    command: Option<Command<S>>,
+    arguments: HashMap<String, ErasedArgument>,
+    root_node: NodeId,
+    nodes: Vec<ParsedCommandNode>,
     range: StringRange,
-    // child: Option<Box<CommandContext<S>>>,
-    // modifier: RedirectModifier<S>,
+    child: Option<Box<CommandContext<'a, S>>>,
+    modifier: Option<&'a dyn RedirectModifier<S>>,
     forks: bool,
 }
+
+impl<'a, S> CommandContext<'a, S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        source: S,
+        input: String,
+        arguments: HashMap<String, ErasedArgument>,
+        command: Option<Command<S>>,
+        root_node: NodeId,
+        nodes: Vec<ParsedCommandNode>,
+        range: StringRange,
+        child: Option<CommandContext<'a, S>>,
+        modifier: Option<&'a dyn RedirectModifier<S>>,
+        forks: bool,
+    ) -> Self {
+        Self {
+            source,
+            input,
+            command,
+            arguments,
+            root_node,
+            nodes,
+            range,
+            child: child.map(Box::new),
+            modifier,
+            forks,
+        }
+    }
+
+    /// A copy of this context that runs as `source` instead.
+    pub fn copy_for(&self, source: S) -> Self
+    where
+        S: Clone,
+    {
+        Self {
+            source,
+            ..self.clone()
+        }
+    }
+
+    pub fn get_source(&self) -> &S {
+        &self.source
+    }
+
+    pub fn get_input(&self) -> &str {
+        &self.input
+    }
+
+    /// The value parsed for the argument `name`, which must have been parsed as a `T`.
+    pub fn get_argument<T: Any>(&self, name: &str) -> Result<&T, BrigadierError> {
+        let argument = self
+            .arguments
+            .get(name)
+            .ok_or_else(|| BrigadierError::NoSuchArgument(name.to_owned()))?;
+
+        argument.get_result().downcast_ref::<T>().ok_or_else(|| {
+            BrigadierError::ArgumentTypeMismatch {
+                name: name.to_owned(),
+                expected: std::any::type_name::<T>(),
+            }
+        })
+    }
+
+    pub fn get_command(&self) -> Option<&Command<S>> {
+        self.command.as_ref()
+    }
+
+    pub fn get_child(&self) -> Option<&CommandContext<'a, S>> {
+        self.child.as_deref()
+    }
+
+    /// The innermost context of the redirect chain, which is this one if nothing was redirected.
+    pub fn get_last_child(&self) -> &CommandContext<'a, S> {
+        let mut result = self;
+        while let Some(child) = result.get_child() {
+            result = child;
+        }
+        result
+    }
+
+    pub fn get_redirect_modifier(&self) -> Option<&'a dyn RedirectModifier<S>> {
+        self.modifier
+    }
+
+    pub fn get_range(&self) -> StringRange {
+        self.range
+    }
+
+    /// The node this context started parsing from: the root, or the target of a redirect.
+    pub fn get_root_node(&self) -> NodeId {
+        self.root_node
+    }
+
+    pub fn get_nodes(&self) -> &[ParsedCommandNode] {
+        &self.nodes
+    }
+
+    pub fn has_nodes(&self) -> bool {
+        !self.nodes.is_empty()
+    }
+
+    pub fn is_forked(&self) -> bool {
+        self.forks
+    }
+}
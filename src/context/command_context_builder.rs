@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use crate::{
+    command::Command, command_dispatcher::CommandDispatcher, redirect_modifier::RedirectModifier,
+    tree::node_id::NodeId,
+};
+
+use super::{
+    command_context::CommandContext, parsed_argument::ErasedArgument,
+    parsed_command_node::ParsedCommandNode, string_range::StringRange,
+    suggestion_context::SuggestionContext,
+};
+
+#[derive(Clone)]
+pub struct CommandContextBuilder<'a, S> {
+    dispatcher: &'a CommandDispatcher<S>,
+    source: S,
+    root_node: NodeId,
+    arguments: HashMap<String, ErasedArgument>,
+    nodes: Vec<ParsedCommandNode>,
+    command: Option<Command<S>>,
+    child: Option<Box<CommandContextBuilder<'a, S>>>,
+    range: StringRange,
+    modifier: Option<&'a dyn RedirectModifier<S>>,
+    forks: bool,
+}
+
+impl<'a, S> CommandContextBuilder<'a, S> {
+    pub fn new(
+        dispatcher: &'a CommandDispatcher<S>,
+        source: S,
+        root_node: NodeId,
+        start: usize,
+    ) -> Self {
+        Self {
+            dispatcher,
+            source,
+            root_node,
+            arguments: HashMap::new(),
+            nodes: Vec::new(),
+            command: None,
+            child: None,
+            range: StringRange::at(start),
+            modifier: None,
+            forks: false,
+        }
+    }
+
+    pub fn with_source(&mut self, source: S) -> &mut Self {
+        self.source = source;
+        self
+    }
+
+    /// A copy of this builder that runs as `source` instead, like [`CommandContext::copy_for`].
+    pub fn copy_for(&self, source: S) -> Self
+    where
+        S: Clone,
+    {
+        Self {
+            source,
+            ..self.clone()
+        }
+    }
+
+    pub fn get_source(&self) -> &S {
+        &self.source
+    }
+
+    pub fn get_dispatcher(&self) -> &'a CommandDispatcher<S> {
+        self.dispatcher
+    }
+
+    pub fn get_root_node(&self) -> NodeId {
+        self.root_node
+    }
+
+    pub fn with_argument(&mut self, name: &str, argument: ErasedArgument) -> &mut Self {
+        self.arguments.insert(name.to_owned(), argument);
+        self
+    }
+
+    pub fn get_arguments(&self) -> &HashMap<String, ErasedArgument> {
+        &self.arguments
+    }
+
+    pub fn with_node(&mut self, node: NodeId, range: StringRange) -> &mut Self {
+        self.nodes.push(ParsedCommandNode::new(node, range));
+        self.range = StringRange::encompassing(&self.range, &range);
+        let node = self.dispatcher.get_node(node);
+        self.modifier = node.get_redirect_modifier();
+        self.forks = node.is_fork();
+        self
+    }
+
+    pub fn get_nodes(&self) -> &[ParsedCommandNode] {
+        &self.nodes
+    }
+
+    pub fn with_command(&mut self, command: Option<Command<S>>) -> &mut Self {
+        self.command = command;
+        self
+    }
+
+    pub fn get_command(&self) -> Option<&Command<S>> {
+        self.command.as_ref()
+    }
+
+    pub fn with_child(&mut self, child: CommandContextBuilder<'a, S>) -> &mut Self {
+        self.child = Some(Box::new(child));
+        self
+    }
+
+    pub fn get_child(&self) -> Option<&CommandContextBuilder<'a, S>> {
+        self.child.as_deref()
+    }
+
+    /// The innermost builder of the redirect chain, which is this one if nothing was redirected.
+    pub fn get_last_child(&self) -> &CommandContextBuilder<'a, S> {
+        let mut result = self;
+        while let Some(child) = result.get_child() {
+            result = child;
+        }
+        result
+    }
+
+    pub fn get_range(&self) -> StringRange {
+        self.range
+    }
+
+    pub fn build(&self, input: &str) -> CommandContext<'a, S>
+    where
+        S: Clone,
+    {
+        CommandContext::new(
+            self.source.clone(),
+            input.to_owned(),
+            self.arguments.clone(),
+            self.command.clone(),
+            self.root_node,
+            self.nodes.clone(),
+            self.range,
+            self.child.as_ref().map(|child| child.build(input)),
+            self.modifier,
+            self.forks,
+        )
+    }
+
+    /// Finds the node whose children complete the input at `cursor`, or `None` if `cursor` is
+    /// before the start of this context.
+    pub fn find_suggestion_context(&self, cursor: usize) -> Option<SuggestionContext> {
+        if self.range.get_start() > cursor {
+            return None;
+        }
+
+        if self.range.get_end() < cursor {
+            if let Some(child) = &self.child {
+                child.find_suggestion_context(cursor)
+            } else if let Some(last) = self.nodes.last() {
+                Some(SuggestionContext::new(
+                    last.get_node(),
+                    last.get_range().get_end() + 1,
+                ))
+            } else {
+                Some(SuggestionContext::new(
+                    self.root_node,
+                    self.range.get_start(),
+                ))
+            }
+        } else {
+            let mut prev = self.root_node;
+            for node in &self.nodes {
+                let node_range = node.get_range();
+                if node_range.get_start() <= cursor && cursor <= node_range.get_end() {
+                    return Some(SuggestionContext::new(prev, node_range.get_start()));
+                }
+                prev = node.get_node();
+            }
+            Some(SuggestionContext::new(prev, self.range.get_start()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::builder::literal_argument_builder::literal;
+
+    use super::*;
+
+    #[test]
+    fn copy_for() {
+        let mut dispatcher: CommandDispatcher<i32> = CommandDispatcher::new();
+        let foo = dispatcher.register(literal("foo"));
+
+        let mut builder = CommandContextBuilder::new(&dispatcher, 1, dispatcher.get_root(), 0);
+        builder.with_node(foo, StringRange::between(0, 3));
+        let copy = builder.copy_for(2);
+
+        assert_eq!(*copy.get_source(), 2);
+        assert_eq!(*builder.get_source(), 1);
+        assert_eq!(copy.get_nodes(), builder.get_nodes());
+        assert_eq!(copy.get_range(), StringRange::between(0, 3));
+        assert_eq!(copy.get_root_node(), dispatcher.get_root());
+    }
+}
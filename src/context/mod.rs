@@ -1,3 +1,6 @@
 pub mod command_context;
+pub mod command_context_builder;
 pub mod parsed_argument;
+pub mod parsed_command_node;
 pub mod string_range;
+pub mod suggestion_context;
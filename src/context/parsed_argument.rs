@@ -1,31 +1,28 @@
-use crate::error::BrigadierError;
+use std::{any::Any, sync::Arc};
 
 use super::string_range::StringRange;
 
-// Why does this have S?
+/// A parsed argument whose type is only known when it is read back with
+/// [`CommandContext::get_argument`](super::command_context::CommandContext::get_argument).
+pub type ErasedArgument = ParsedArgument<Arc<dyn Any + Send + Sync>>;
 
-#[derive(Debug, PartialEq)]
-pub struct ParsedArgument<S, T> {
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedArgument<T> {
     range: StringRange,
-    result: Result<T, BrigadierError>,
-    _marker: std::marker::PhantomData<S>,
+    result: T,
 }
 
-impl<S, T> ParsedArgument<S, T> {
-    pub fn new(start: usize, end: usize, result: Result<T, BrigadierError>) -> Self {
+impl<T> ParsedArgument<T> {
+    pub fn new(start: usize, end: usize, result: T) -> Self {
         let range = StringRange::new(start, end);
-        Self {
-            range,
-            result,
-            _marker: std::marker::PhantomData,
-        }
+        Self { range, result }
     }
 
     pub fn get_range(&self) -> StringRange {
         self.range
     }
 
-    pub fn get_result(&self) -> &Result<T, BrigadierError> {
+    pub fn get_result(&self) -> &T {
         &self.result
     }
 }
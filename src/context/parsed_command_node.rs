@@ -0,0 +1,23 @@
+use crate::tree::node_id::NodeId;
+
+use super::string_range::StringRange;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsedCommandNode {
+    node: NodeId,
+    range: StringRange,
+}
+
+impl ParsedCommandNode {
+    pub fn new(node: NodeId, range: StringRange) -> Self {
+        Self { node, range }
+    }
+
+    pub fn get_node(&self) -> NodeId {
+        self.node
+    }
+
+    pub fn get_range(&self) -> StringRange {
+        self.range
+    }
+}
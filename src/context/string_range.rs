@@ -37,11 +37,15 @@ impl StringRange {
     }
 
     pub fn get_from_string_reader(&self, reader: StringReader) -> String {
-        reader.get_string()[self.start..self.end].to_string()
+        self.get_from_string(reader.get_string())
     }
 
+    /// The part of `input` this range covers, with any end that falls inside a character moved
+    /// back to the start of that character.
     pub fn get_from_string(&self, input: &str) -> String {
-        input[self.start..self.end].to_string()
+        let end = input.floor_char_boundary(self.end);
+        let start = input.floor_char_boundary(self.start).min(end);
+        input[start..end].to_string()
     }
 
     pub fn is_empty(&self) -> bool {
@@ -58,3 +62,23 @@ impl Display for StringRange {
         write!(f, "StringRange{{start={}, end={}}}", self.start, self.end)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_from_string() {
+        assert_eq!(StringRange::between(4, 7).get_from_string("say hi!"), "hi!");
+    }
+
+    #[test]
+    fn get_from_string_multibyte() {
+        assert_eq!(StringRange::between(4, 8).get_from_string("say äö!"), "äö");
+        assert_eq!(StringRange::between(5, 7).get_from_string("say äö!"), "ä");
+        assert_eq!(
+            StringRange::between(5, 20).get_from_string("say äö!"),
+            "äö!"
+        );
+    }
+}
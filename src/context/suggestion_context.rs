@@ -0,0 +1,13 @@
+use crate::tree::node_id::NodeId;
+
+/// The node whose children should be asked for suggestions, and where their input starts.
+pub struct SuggestionContext {
+    pub parent: NodeId,
+    pub start_pos: usize,
+}
+
+impl SuggestionContext {
+    pub fn new(parent: NodeId, start_pos: usize) -> Self {
+        Self { parent, start_pos }
+    }
+}
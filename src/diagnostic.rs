@@ -0,0 +1,301 @@
+use unicode_width::UnicodeWidthChar;
+
+use crate::{context::string_range::StringRange, exceptions::CommandSyntaxException};
+
+const DEFAULT_MAX_WIDTH: usize = 80;
+const ELLIPSIS: &str = "...";
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Renders an error under the line of input it applies to, underlining the failing range:
+///
+/// ```text
+/// give Steve 640
+///            ^~~
+/// error: Integer must not be more than 64, found 640
+/// ```
+///
+/// Multi-line input gets line numbers, and lines wider than the maximum width are cut down to
+/// the part around the error. The underline is aligned by display width, so it stays under the
+/// right characters after wide ones like CJK or emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticRenderer {
+    colors: bool,
+    max_width: usize,
+}
+
+impl DiagnosticRenderer {
+    pub fn new() -> Self {
+        Self {
+            colors: false,
+            max_width: DEFAULT_MAX_WIDTH,
+        }
+    }
+
+    /// Whether to highlight the output with ANSI escape codes.
+    pub fn with_colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// The most characters of input to show on one line, not counting the ellipses.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = max_width.max(1);
+        self
+    }
+
+    pub fn render(&self, input: &str, range: StringRange, message: &str) -> String {
+        let start = range.get_start().min(input.len());
+        let end = range.get_end().clamp(start, input.len());
+
+        let bytes = input.as_bytes();
+        let line_start = bytes[..start]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |index| index + 1);
+        let line_end = bytes[line_start..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(input.len(), |index| line_start + index);
+        let line = input[line_start..line_end].trim_end_matches('\r');
+        let line_number = bytes[..line_start].iter().filter(|&&b| b == b'\n').count() + 1;
+
+        let chars: Vec<char> = line
+            .chars()
+            .map(|c| if c == '\t' { ' ' } else { c })
+            .collect();
+        let column = char_column(line, start - line_start);
+        let end_column = char_column(line, end.min(line_end) - line_start).max(column + 1);
+
+        let window_start = column
+            .saturating_sub(self.max_width / 2)
+            .min(chars.len().saturating_sub(self.max_width));
+        let window_end = (window_start + self.max_width).min(chars.len());
+
+        let mut text = String::new();
+        if window_start > 0 {
+            text.push_str(ELLIPSIS);
+        }
+        text.extend(&chars[window_start..window_end]);
+        if window_end < chars.len() {
+            text.push_str(ELLIPSIS);
+        }
+
+        let underline_end = end_column.min(window_end).max(column);
+        let padding = display_width(&chars[window_start..column])
+            + if window_start > 0 { ELLIPSIS.len() } else { 0 };
+        let width = display_width(&chars[column..underline_end]).max(1);
+        let underline = format!(
+            "{}{}{}{}",
+            " ".repeat(padding),
+            self.paint(RED),
+            "^".to_owned() + &"~".repeat(width - 1),
+            self.paint(RESET)
+        );
+
+        let mut output = String::new();
+        if input.contains('\n') {
+            let gutter = line_number.to_string();
+            let blank = " ".repeat(gutter.len());
+            output.push_str(&format!(
+                "{}{} |{} {}\n",
+                self.paint(BLUE),
+                gutter,
+                self.paint(RESET),
+                text
+            ));
+            output.push_str(&format!(
+                "{}{} |{} {}\n",
+                self.paint(BLUE),
+                blank,
+                self.paint(RESET),
+                underline
+            ));
+        } else {
+            output.push_str(&text);
+            output.push('\n');
+            output.push_str(&underline);
+            output.push('\n');
+        }
+        output.push_str(&self.render_message(message));
+        output
+    }
+
+    /// Renders `exception` at its range, or just its message if it has no input.
+    pub fn render_exception(&self, exception: &CommandSyntaxException) -> String {
+        let message = exception.get_type().to_string();
+        match (exception.get_input(), exception.get_range()) {
+            (Some(input), Some(range)) => self.render(input, range, &message),
+            _ => self.render_message(&message),
+        }
+    }
+
+    fn render_message(&self, message: &str) -> String {
+        format!(
+            "{}error{}{}: {}{}",
+            self.paint(RED),
+            self.paint(RESET),
+            self.paint(BOLD),
+            message,
+            self.paint(RESET)
+        )
+    }
+
+    fn paint(&self, code: &'static str) -> &'static str {
+        if self.colors {
+            code
+        } else {
+            ""
+        }
+    }
+}
+
+impl Default for DiagnosticRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The number of terminal columns `chars` take up, so wide characters like CJK and emoji count
+/// twice.
+fn display_width(chars: &[char]) -> usize {
+    chars
+        .iter()
+        .map(|&c| UnicodeWidthChar::width(c).unwrap_or(0))
+        .sum()
+}
+
+/// The number of characters in `line` before the byte `offset`.
+fn char_column(line: &str, offset: usize) -> usize {
+    line.char_indices()
+        .take_while(|(index, _)| *index < offset)
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::BrigadierError;
+
+    use super::*;
+
+    #[test]
+    fn render_range() {
+        let output = DiagnosticRenderer::new().render(
+            "give Steve 640",
+            StringRange::between(11, 14),
+            "Integer must not be more than 64, found 640",
+        );
+        assert_eq!(
+            output,
+            "give Steve 640\n           ^~~\nerror: Integer must not be more than 64, found 640"
+        );
+    }
+
+    #[test]
+    fn render_cursor_at_end() {
+        let output = DiagnosticRenderer::new().render("tp", StringRange::at(2), "Expected '.'");
+        assert_eq!(output, "tp\n  ^\nerror: Expected '.'");
+    }
+
+    #[test]
+    fn render_multibyte() {
+        let output =
+            DiagnosticRenderer::new().render("say héllo wörld", StringRange::between(11, 17), "x");
+        assert_eq!(output, "say héllo wörld\n          ^~~~~\nerror: x");
+    }
+
+    #[test]
+    fn render_wide_characters() {
+        let output =
+            DiagnosticRenderer::new().render("give 東京 64", StringRange::between(5, 11), "oops");
+        assert_eq!(output, "give 東京 64\n     ^~~~\nerror: oops");
+
+        let output = DiagnosticRenderer::new().render("say 🎉 x", StringRange::at(9), "oops");
+        assert_eq!(output, "say 🎉 x\n       ^\nerror: oops");
+    }
+
+    #[test]
+    fn render_colors() {
+        let output = DiagnosticRenderer::new().with_colors(true).render(
+            "foo",
+            StringRange::between(0, 3),
+            "Unknown command",
+        );
+        assert_eq!(
+            output,
+            "foo\n\x1b[1;31m^~~\x1b[0m\n\x1b[1;31merror\x1b[0m\x1b[1m: Unknown command\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn render_long_input() {
+        let input = format!("{} bad {}", "a".repeat(50), "b".repeat(50));
+        let output = DiagnosticRenderer::new().with_max_width(20).render(
+            &input,
+            StringRange::between(51, 54),
+            "oops",
+        );
+        assert_eq!(
+            output,
+            format!(
+                "...{} bad {}...\n{}^~~\nerror: oops",
+                "a".repeat(9),
+                "b".repeat(6),
+                " ".repeat(13)
+            )
+        );
+    }
+
+    #[test]
+    fn render_multiline() {
+        let input = "say hi\ngive Steve 640\nkill @e";
+        let output = DiagnosticRenderer::new().render(input, StringRange::between(18, 21), "oops");
+        assert_eq!(
+            output,
+            "2 | give Steve 640\n  |            ^~~\nerror: oops"
+        );
+    }
+
+    #[test]
+    fn render_range_spanning_lines() {
+        let input = "say hi\nthere";
+        let output = DiagnosticRenderer::new().render(input, StringRange::between(4, 10), "oops");
+        assert_eq!(output, "1 | say hi\n  |     ^~\nerror: oops");
+    }
+
+    #[test]
+    fn render_exception() {
+        let exception =
+            CommandSyntaxException::with_context(BrigadierError::ExpectedInt, "give Steve x", 11);
+        assert_eq!(
+            DiagnosticRenderer::new().render_exception(&exception),
+            "give Steve x\n           ^\nerror: Expected integer"
+        );
+    }
+
+    #[test]
+    fn render_exception_range() {
+        let exception = CommandSyntaxException::with_context(
+            BrigadierError::InvalidEscapeSequence(r"\x4".to_owned()),
+            r#"say "a\x4""#,
+            6,
+        )
+        .with_end(9);
+        assert_eq!(
+            DiagnosticRenderer::new().render_exception(&exception),
+            "say \"a\\x4\"\n      ^~~\nerror: Invalid escape sequence '\\x4' in quoted string"
+        );
+    }
+
+    #[test]
+    fn render_exception_without_input() {
+        let exception = BrigadierError::DispatcherUnknownCommand.create();
+        assert_eq!(
+            DiagnosticRenderer::new().render_exception(&exception),
+            "error: Unknown command"
+        );
+    }
+}
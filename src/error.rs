@@ -1,4 +1,8 @@
-#[derive(Debug, PartialEq)]
+use std::fmt::{self, Display};
+
+use crate::message_catalog::{english_pattern, format_pattern};
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum BrigadierError {
     EOF,
     ExpectedSymbol(char),
@@ -11,19 +15,383 @@ pub enum BrigadierError {
     ExpectedStartOfQuote,
     ExpectedEndOfQuote,
 
-    InvalidInt,
-    InvalidLong,
-    InvalidDouble,
-    InvalidFloat,
+    InvalidInt(String),
+    InvalidLong(String),
+    InvalidDouble(String),
+    InvalidFloat(String),
+    /// The character after a backslash that doesn't start an escape.
     InvalidEscape(char),
-    InvalidBool,
-
-    IntegerTooLow { result: i32, min: i32 },
-    IntegerTooHigh { result: i32, max: i32 },
-    DoubleTooLow { result: f64, min: f64 },
-    DoubleTooHigh { result: f64, max: f64 },
-    FloatTooLow { result: f32, min: f32 },
-    FloatTooHigh { result: f32, max: f32 },
-    LongTooLow { result: i64, min: i64 },
-    LongTooHigh { result: i64, max: i64 },
+    /// A malformed rich escape such as `\x4` or `\u{110000}`, or any invalid escape when the
+    /// escape character isn't a backslash, including the escape character.
+    InvalidEscapeSequence(String),
+    InvalidBool(String),
+
+    IntegerTooLow {
+        result: i32,
+        min: i32,
+    },
+    IntegerTooHigh {
+        result: i32,
+        max: i32,
+    },
+    DoubleTooLow {
+        result: f64,
+        min: f64,
+    },
+    DoubleTooHigh {
+        result: f64,
+        max: f64,
+    },
+    FloatTooLow {
+        result: f32,
+        min: f32,
+    },
+    FloatTooHigh {
+        result: f32,
+        max: f32,
+    },
+    LongTooLow {
+        result: i64,
+        min: i64,
+    },
+    LongTooHigh {
+        result: i64,
+        max: i64,
+    },
+
+    LiteralIncorrect(String),
+
+    DispatcherUnknownCommand,
+    DispatcherUnknownArgument,
+    DispatcherExpectedArgumentSeparator,
+
+    RequiresAsyncExecution,
+
+    /// Raised through a [`SimpleCommandExceptionType`](crate::exceptions::SimpleCommandExceptionType)
+    /// or [`DynamicCommandExceptionType`](crate::exceptions::DynamicCommandExceptionType).
+    Custom(String),
+    /// Like [`Custom`](Self::Custom), but with a message looked up in a
+    /// [`MessageCatalog`](crate::message_catalog::MessageCatalog) by `key`.
+    Translatable {
+        key: String,
+        args: Vec<String>,
+    },
+
+    NoSuchArgument(String),
+    ArgumentTypeMismatch {
+        name: String,
+        expected: &'static str,
+    },
+}
+
+impl BrigadierError {
+    /// A stable key for this error's message, e.g. `parsing.int.expected`.
+    pub fn get_translation_key(&self) -> &str {
+        match self {
+            BrigadierError::EOF => "parsing.eof",
+            BrigadierError::ExpectedSymbol(_) => "parsing.expected",
+            BrigadierError::ExpectedInt => "parsing.int.expected",
+            BrigadierError::ExpectedLong => "parsing.long.expected",
+            BrigadierError::ExpectedDouble => "parsing.double.expected",
+            BrigadierError::ExpectedFloat => "parsing.float.expected",
+            BrigadierError::ExpectedBool => "parsing.bool.expected",
+            BrigadierError::ExpectedUnquotedString => "parsing.unquoted_string.expected",
+            BrigadierError::ExpectedStartOfQuote => "parsing.quote.expected.start",
+            BrigadierError::ExpectedEndOfQuote => "parsing.quote.expected.end",
+
+            BrigadierError::InvalidInt(_) => "parsing.int.invalid",
+            BrigadierError::InvalidLong(_) => "parsing.long.invalid",
+            BrigadierError::InvalidDouble(_) => "parsing.double.invalid",
+            BrigadierError::InvalidFloat(_) => "parsing.float.invalid",
+            BrigadierError::InvalidEscape(_) => "parsing.quote.escape",
+            BrigadierError::InvalidEscapeSequence(_) => "parsing.quote.escape.sequence",
+            BrigadierError::InvalidBool(_) => "parsing.bool.invalid",
+
+            BrigadierError::IntegerTooLow { .. } => "argument.integer.low",
+            BrigadierError::IntegerTooHigh { .. } => "argument.integer.big",
+            BrigadierError::DoubleTooLow { .. } => "argument.double.low",
+            BrigadierError::DoubleTooHigh { .. } => "argument.double.big",
+            BrigadierError::FloatTooLow { .. } => "argument.float.low",
+            BrigadierError::FloatTooHigh { .. } => "argument.float.big",
+            BrigadierError::LongTooLow { .. } => "argument.long.low",
+            BrigadierError::LongTooHigh { .. } => "argument.long.big",
+
+            BrigadierError::LiteralIncorrect(_) => "argument.literal.incorrect",
+
+            BrigadierError::DispatcherUnknownCommand => "command.unknown.command",
+            BrigadierError::DispatcherUnknownArgument => "command.unknown.argument",
+            BrigadierError::DispatcherExpectedArgumentSeparator => "command.expected.separator",
+
+            BrigadierError::RequiresAsyncExecution => "command.requires_async",
+
+            BrigadierError::Custom(_) => "command.custom",
+            BrigadierError::Translatable { key, .. } => key,
+
+            BrigadierError::NoSuchArgument(_) => "argument.unknown",
+            BrigadierError::ArgumentTypeMismatch { .. } => "argument.type_mismatch",
+        }
+    }
+
+    /// One error of every variant, for checking every key has a message. Adding a variant
+    /// breaks the match below until the variant is listed here too.
+    #[cfg(test)]
+    pub(crate) fn examples() -> Vec<BrigadierError> {
+        use BrigadierError::*;
+
+        let examples = vec![
+            EOF,
+            ExpectedSymbol(' '),
+            ExpectedInt,
+            ExpectedLong,
+            ExpectedDouble,
+            ExpectedFloat,
+            ExpectedBool,
+            ExpectedUnquotedString,
+            ExpectedStartOfQuote,
+            ExpectedEndOfQuote,
+            InvalidInt("x".to_owned()),
+            InvalidLong("x".to_owned()),
+            InvalidDouble("x".to_owned()),
+            InvalidFloat("x".to_owned()),
+            InvalidEscape('q'),
+            InvalidEscapeSequence(r"\x4".to_owned()),
+            InvalidBool("x".to_owned()),
+            IntegerTooLow { result: 0, min: 1 },
+            IntegerTooHigh { result: 1, max: 0 },
+            DoubleTooLow {
+                result: 0.0,
+                min: 1.0,
+            },
+            DoubleTooHigh {
+                result: 1.0,
+                max: 0.0,
+            },
+            FloatTooLow {
+                result: 0.0,
+                min: 1.0,
+            },
+            FloatTooHigh {
+                result: 1.0,
+                max: 0.0,
+            },
+            LongTooLow { result: 0, min: 1 },
+            LongTooHigh { result: 1, max: 0 },
+            LiteralIncorrect("foo".to_owned()),
+            DispatcherUnknownCommand,
+            DispatcherUnknownArgument,
+            DispatcherExpectedArgumentSeparator,
+            RequiresAsyncExecution,
+            Custom("oops".to_owned()),
+            Translatable {
+                key: "command.custom".to_owned(),
+                args: vec!["oops".to_owned()],
+            },
+            NoSuchArgument("foo".to_owned()),
+            ArgumentTypeMismatch {
+                name: "foo".to_owned(),
+                expected: "i32",
+            },
+        ];
+        for example in &examples {
+            match example {
+                EOF
+                | ExpectedSymbol(_)
+                | ExpectedInt
+                | ExpectedLong
+                | ExpectedDouble
+                | ExpectedFloat
+                | ExpectedBool
+                | ExpectedUnquotedString
+                | ExpectedStartOfQuote
+                | ExpectedEndOfQuote
+                | InvalidInt(_)
+                | InvalidLong(_)
+                | InvalidDouble(_)
+                | InvalidFloat(_)
+                | InvalidEscape(_)
+                | InvalidEscapeSequence(_)
+                | InvalidBool(_)
+                | IntegerTooLow { .. }
+                | IntegerTooHigh { .. }
+                | DoubleTooLow { .. }
+                | DoubleTooHigh { .. }
+                | FloatTooLow { .. }
+                | FloatTooHigh { .. }
+                | LongTooLow { .. }
+                | LongTooHigh { .. }
+                | LiteralIncorrect(_)
+                | DispatcherUnknownCommand
+                | DispatcherUnknownArgument
+                | DispatcherExpectedArgumentSeparator
+                | RequiresAsyncExecution
+                | Custom(_)
+                | Translatable { .. }
+                | NoSuchArgument(_)
+                | ArgumentTypeMismatch { .. } => {}
+            }
+        }
+        examples
+    }
+
+    /// The values filled into the message for [`get_translation_key`](Self::get_translation_key).
+    pub fn get_translation_args(&self) -> Vec<String> {
+        match self {
+            BrigadierError::ExpectedSymbol(c) | BrigadierError::InvalidEscape(c) => {
+                vec![c.to_string()]
+            }
+
+            BrigadierError::InvalidInt(value)
+            | BrigadierError::InvalidLong(value)
+            | BrigadierError::InvalidDouble(value)
+            | BrigadierError::InvalidFloat(value)
+            | BrigadierError::InvalidEscapeSequence(value)
+            | BrigadierError::InvalidBool(value)
+            | BrigadierError::LiteralIncorrect(value)
+            | BrigadierError::Custom(value)
+            | BrigadierError::NoSuchArgument(value) => vec![value.clone()],
+
+            BrigadierError::IntegerTooLow { result, min } => {
+                vec![min.to_string(), result.to_string()]
+            }
+            BrigadierError::IntegerTooHigh { result, max } => {
+                vec![max.to_string(), result.to_string()]
+            }
+            BrigadierError::DoubleTooLow { result, min } => {
+                vec![java_double(*min), java_double(*result)]
+            }
+            BrigadierError::DoubleTooHigh { result, max } => {
+                vec![java_double(*max), java_double(*result)]
+            }
+            BrigadierError::FloatTooLow { result, min } => {
+                vec![java_float(*min), java_float(*result)]
+            }
+            BrigadierError::FloatTooHigh { result, max } => {
+                vec![java_float(*max), java_float(*result)]
+            }
+            BrigadierError::LongTooLow { result, min } => vec![min.to_string(), result.to_string()],
+            BrigadierError::LongTooHigh { result, max } => {
+                vec![max.to_string(), result.to_string()]
+            }
+
+            BrigadierError::ArgumentTypeMismatch { name, expected } => {
+                vec![name.clone(), expected.to_string()]
+            }
+
+            BrigadierError::Translatable { args, .. } => args.clone(),
+
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Formats `value` like Java's `Double.toString`, which Mojang's messages are worded with:
+/// `10.0` rather than Rust's `10`, and `1.0E7` from ten million on.
+fn java_double(value: f64) -> String {
+    java_decimal(value, value.to_string(), format!("{value:e}"))
+}
+
+/// Formats `value` like Java's `Float.toString`.
+fn java_float(value: f32) -> String {
+    java_decimal(value.into(), value.to_string(), format!("{value:e}"))
+}
+
+/// Java's decimal format, given Rust's shortest plain and scientific forms of the same value.
+fn java_decimal(value: f64, plain: String, scientific: String) -> String {
+    if value.is_nan() {
+        return "NaN".to_owned();
+    }
+    if value.is_infinite() {
+        return if value > 0.0 { "Infinity" } else { "-Infinity" }.to_owned();
+    }
+
+    let magnitude = value.abs();
+    let (mantissa, exponent) = if magnitude == 0.0 || (1e-3..1e7).contains(&magnitude) {
+        (plain, None)
+    } else {
+        match scientific.split_once('e') {
+            Some((mantissa, exponent)) => (mantissa.to_owned(), Some(exponent.to_owned())),
+            None => (scientific, None),
+        }
+    };
+
+    let mut result = mantissa;
+    if !result.contains('.') {
+        result.push_str(".0");
+    }
+    if let Some(exponent) = exponent {
+        result.push('E');
+        result.push_str(&exponent);
+    }
+    result
+}
+
+impl Display for BrigadierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = self.get_translation_key();
+        let pattern = english_pattern(key).unwrap_or(key);
+        f.write_str(&format_pattern(pattern, &self.get_translation_args()))
+    }
+}
+
+impl std::error::Error for BrigadierError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display_range() {
+        let error = BrigadierError::IntegerTooLow { result: -5, min: 0 };
+        assert_eq!(
+            error.to_string(),
+            "Integer must not be less than 0, found -5"
+        );
+
+        let error = BrigadierError::DoubleTooHigh {
+            result: 10.5,
+            max: 10.0,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Double must not be more than 10.0, found 10.5"
+        );
+    }
+
+    #[test]
+    fn display_float_java_style() {
+        assert_eq!(java_double(-3.0), "-3.0");
+        assert_eq!(java_double(0.001), "0.001");
+        assert_eq!(java_double(0.0001), "1.0E-4");
+        assert_eq!(java_double(1.5e7), "1.5E7");
+        assert_eq!(java_double(f64::NEG_INFINITY), "-Infinity");
+        assert_eq!(java_float(0.1), "0.1");
+        assert_eq!(java_float(f32::MAX), "3.4028235E38");
+
+        let error = BrigadierError::FloatTooLow {
+            result: -1.0,
+            min: 0.5,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Float must not be less than 0.5, found -1.0"
+        );
+    }
+
+    #[test]
+    fn display_invalid_escape() {
+        let error = BrigadierError::InvalidEscape('q');
+        assert_eq!(
+            error.to_string(),
+            "Invalid escape sequence '\\q' in quoted string"
+        );
+    }
+
+    #[test]
+    fn display_invalid_value() {
+        let error = BrigadierError::InvalidBool("tuesday".to_owned());
+        assert_eq!(
+            error.to_string(),
+            "Invalid bool, expected true or false but found 'tuesday'"
+        );
+    }
 }
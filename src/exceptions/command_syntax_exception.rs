@@ -0,0 +1,223 @@
+use std::{
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+use crate::{
+    context::string_range::StringRange, error::BrigadierError, string_reader::StringReader,
+};
+
+const CONTEXT_AMOUNT: usize = 10;
+
+/// A [`BrigadierError`] together with the input and cursor position it was raised at, if known.
+/// The input is shared with the reader it came from, so creating one doesn't copy it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandSyntaxException {
+    error: BrigadierError,
+    input: Option<Arc<str>>,
+    cursor: Option<usize>,
+    end: Option<usize>,
+}
+
+impl CommandSyntaxException {
+    pub fn new(error: BrigadierError) -> Self {
+        Self {
+            error,
+            input: None,
+            cursor: None,
+            end: None,
+        }
+    }
+
+    pub fn with_context(error: BrigadierError, input: impl Into<Arc<str>>, cursor: usize) -> Self {
+        Self {
+            error,
+            input: Some(input.into()),
+            cursor: Some(cursor),
+            end: None,
+        }
+    }
+
+    /// Marks the input from the cursor up to `end` as the part that failed.
+    pub fn with_end(mut self, end: usize) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    pub fn get_type(&self) -> &BrigadierError {
+        &self.error
+    }
+
+    pub fn get_input(&self) -> Option<&str> {
+        self.input.as_deref()
+    }
+
+    pub fn get_cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// The cursor as a number of characters rather than bytes, which is what messages show.
+    pub fn get_position(&self) -> Option<usize> {
+        let cursor = self.cursor?;
+        Some(match &self.input {
+            Some(input) => input[..input.floor_char_boundary(cursor)].chars().count(),
+            None => cursor,
+        })
+    }
+
+    /// The part of the input that failed, which is empty unless an end was given.
+    pub fn get_range(&self) -> Option<StringRange> {
+        let cursor = self.cursor?;
+        Some(StringRange::between(
+            cursor,
+            self.end.unwrap_or(cursor).max(cursor),
+        ))
+    }
+
+    /// Up to 10 characters of input leading up to the cursor, e.g. `...ve 64<--[HERE]`.
+    pub fn get_context(&self) -> Option<String> {
+        let (input, cursor) = (self.input.as_deref()?, self.cursor?);
+        let cursor = input.floor_char_boundary(cursor);
+
+        let mut builder = String::new();
+        let start = input[..cursor]
+            .char_indices()
+            .rev()
+            .nth(CONTEXT_AMOUNT - 1)
+            .map_or(0, |(index, _)| index);
+        if start > 0 {
+            builder.push_str("...");
+        }
+        builder.push_str(&input[start..cursor]);
+        builder.push_str("<--[HERE]");
+        Some(builder)
+    }
+}
+
+/// The message followed by its position, e.g. `Unknown command at position 4: foo <--[HERE]`.
+impl Display for CommandSyntaxException {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if let (Some(position), Some(context)) = (self.get_position(), self.get_context()) {
+            write!(f, " at position {}: {}", position, context)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CommandSyntaxException {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<BrigadierError> for CommandSyntaxException {
+    fn from(error: BrigadierError) -> Self {
+        Self::new(error)
+    }
+}
+
+impl BrigadierError {
+    /// This error without any context.
+    pub fn create(self) -> CommandSyntaxException {
+        CommandSyntaxException::new(self)
+    }
+
+    /// This error at the reader's current position.
+    pub fn create_with_context(self, reader: &StringReader) -> CommandSyntaxException {
+        CommandSyntaxException::with_context(self, reader.get_shared_string(), reader.get_cursor())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display_multibyte_position() {
+        let exception = CommandSyntaxException::with_context(
+            BrigadierError::ExpectedInt,
+            "give Jürgen 640",
+            13,
+        );
+        assert_eq!(exception.get_position(), Some(12));
+        assert_eq!(
+            exception.to_string(),
+            "Expected integer at position 12: ...ve Jürgen <--[HERE]"
+        );
+    }
+
+    #[test]
+    fn create_with_context_shares_input() {
+        let reader = StringReader::new("foo bar");
+        let exception = BrigadierError::ExpectedInt.create_with_context(&reader);
+        assert!(Arc::ptr_eq(
+            exception.input.as_ref().unwrap(),
+            &reader.get_shared_string()
+        ));
+    }
+
+    #[test]
+    fn get_context_without_input() {
+        let exception = BrigadierError::ExpectedInt.create();
+        assert_eq!(exception.get_context(), None);
+    }
+
+    #[test]
+    fn get_context_short() {
+        let exception =
+            CommandSyntaxException::with_context(BrigadierError::ExpectedInt, "foo 64", 4);
+        assert_eq!(exception.get_context(), Some("foo <--[HERE]".to_owned()));
+    }
+
+    #[test]
+    fn get_context_truncated() {
+        let exception =
+            CommandSyntaxException::with_context(BrigadierError::ExpectedInt, "give Steve 64", 13);
+        assert_eq!(
+            exception.get_context(),
+            Some("...e Steve 64<--[HERE]".to_owned())
+        );
+    }
+
+    #[test]
+    fn get_context_exactly_ten() {
+        let exception =
+            CommandSyntaxException::with_context(BrigadierError::ExpectedInt, "0123456789", 10);
+        assert_eq!(
+            exception.get_context(),
+            Some("0123456789<--[HERE]".to_owned())
+        );
+    }
+
+    #[test]
+    fn get_context_cursor_past_end() {
+        let exception = CommandSyntaxException::with_context(BrigadierError::ExpectedInt, "foo", 8);
+        assert_eq!(exception.get_context(), Some("foo<--[HERE]".to_owned()));
+    }
+
+    #[test]
+    fn display_without_context() {
+        let exception = BrigadierError::DispatcherUnknownCommand.create();
+        assert_eq!(exception.to_string(), "Unknown command");
+    }
+
+    #[test]
+    fn display_with_context() {
+        let exception =
+            CommandSyntaxException::with_context(BrigadierError::ExpectedInt, "foo bar", 4);
+        assert_eq!(
+            exception.to_string(),
+            "Expected integer at position 4: foo <--[HERE]"
+        );
+    }
+
+    #[test]
+    fn create_with_context() {
+        let mut reader = StringReader::new("foo bar");
+        reader.set_cursor(4);
+        let exception = BrigadierError::ExpectedInt.create_with_context(&reader);
+        assert_eq!(exception.get_input(), Some("foo bar"));
+        assert_eq!(exception.get_cursor(), Some(4));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{error::BrigadierError, string_reader::StringReader};
+
+use super::CommandSyntaxException;
+
+/// An error whose message is built from arguments, such as "Player Steve not found".
+///
+/// Errors taking several arguments use a tuple, e.g. `DynamicCommandExceptionType<(i64, i64)>`,
+/// and a variable number of arguments can be passed as a slice.
+pub struct DynamicCommandExceptionType<A: ?Sized> {
+    function: Box<dyn Fn(&A) -> BrigadierError + Send + Sync>,
+}
+
+impl<A: ?Sized> DynamicCommandExceptionType<A> {
+    /// An error whose message `function` formats directly, the same in every locale.
+    pub fn new(function: impl Fn(&A) -> String + Send + Sync + 'static) -> Self {
+        Self {
+            function: Box::new(move |arg| BrigadierError::Custom(function(arg))),
+        }
+    }
+
+    /// An error whose message is looked up by `key` in a
+    /// [`MessageCatalog`](crate::message_catalog::MessageCatalog), filled in with the values
+    /// `args` returns.
+    pub fn translatable(
+        key: impl Into<String>,
+        args: impl Fn(&A) -> Vec<String> + Send + Sync + 'static,
+    ) -> Self {
+        let key = key.into();
+        Self {
+            function: Box::new(move |arg| BrigadierError::Translatable {
+                key: key.clone(),
+                args: args(arg),
+            }),
+        }
+    }
+
+    pub fn create(&self, arg: &A) -> CommandSyntaxException {
+        (self.function)(arg).create()
+    }
+
+    pub fn create_with_context(&self, reader: &StringReader, arg: &A) -> CommandSyntaxException {
+        (self.function)(arg).create_with_context(reader)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::message_catalog::{LangMessageCatalog, MessageCatalog};
+
+    use super::*;
+
+    #[test]
+    fn create_one_argument() {
+        let exception: DynamicCommandExceptionType<str> =
+            DynamicCommandExceptionType::new(|name| format!("Player {} not found", name));
+        assert_eq!(
+            exception.create("Steve").get_type(),
+            &BrigadierError::Custom("Player Steve not found".to_owned())
+        );
+    }
+
+    #[test]
+    fn create_several_arguments() {
+        let exception = DynamicCommandExceptionType::new(|(have, need): &(i64, i64)| {
+            format!("You have {} but need {}", have, need)
+        });
+        assert_eq!(
+            exception.create(&(3, 5)).get_type(),
+            &BrigadierError::Custom("You have 3 but need 5".to_owned())
+        );
+    }
+
+    #[test]
+    fn create_any_number_of_arguments() {
+        let exception: DynamicCommandExceptionType<[&str]> =
+            DynamicCommandExceptionType::new(|names: &[&str]| {
+                format!("Unknown: {}", names.join(", "))
+            });
+        assert_eq!(
+            exception.create(&["a", "b", "c"]).get_type(),
+            &BrigadierError::Custom("Unknown: a, b, c".to_owned())
+        );
+    }
+
+    #[test]
+    fn create_translatable() {
+        let exception: DynamicCommandExceptionType<str> =
+            DynamicCommandExceptionType::translatable("player.not_found", |name: &str| {
+                vec![name.to_owned()]
+            });
+        let error = exception.create("Steve");
+        assert_eq!(
+            error.get_type(),
+            &BrigadierError::Translatable {
+                key: "player.not_found".to_owned(),
+                args: vec!["Steve".to_owned()],
+            }
+        );
+
+        let mut catalog = LangMessageCatalog::new();
+        catalog.add_translations(
+            "nl_nl",
+            HashMap::from([(
+                "player.not_found".to_owned(),
+                "Speler %s niet gevonden".to_owned(),
+            )]),
+        );
+        assert_eq!(
+            catalog.render_error("nl_nl", error.get_type()),
+            "Speler Steve niet gevonden"
+        );
+    }
+}
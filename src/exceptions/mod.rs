@@ -0,0 +1,7 @@
+pub mod command_syntax_exception;
+pub mod dynamic_command_exception_type;
+pub mod simple_command_exception_type;
+
+pub use command_syntax_exception::CommandSyntaxException;
+pub use dynamic_command_exception_type::DynamicCommandExceptionType;
+pub use simple_command_exception_type::SimpleCommandExceptionType;
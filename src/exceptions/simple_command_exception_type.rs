@@ -0,0 +1,91 @@
+use crate::{error::BrigadierError, string_reader::StringReader};
+
+use super::CommandSyntaxException;
+
+/// An error with a fixed message, such as "You don't have permission to do that".
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimpleCommandExceptionType {
+    error: BrigadierError,
+}
+
+impl SimpleCommandExceptionType {
+    /// An error with a literal message, shown the same in every locale.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            error: BrigadierError::Custom(message.into()),
+        }
+    }
+
+    /// An error whose message is looked up by `key` in a
+    /// [`MessageCatalog`](crate::message_catalog::MessageCatalog).
+    pub fn translatable(key: impl Into<String>) -> Self {
+        Self {
+            error: BrigadierError::Translatable {
+                key: key.into(),
+                args: Vec::new(),
+            },
+        }
+    }
+
+    /// The English message, or the key of a translatable error that has none.
+    pub fn get_message(&self) -> String {
+        self.error.to_string()
+    }
+
+    pub fn create(&self) -> CommandSyntaxException {
+        self.error.clone().create()
+    }
+
+    pub fn create_with_context(&self, reader: &StringReader) -> CommandSyntaxException {
+        self.error.clone().create_with_context(reader)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::message_catalog::{LangMessageCatalog, MessageCatalog};
+
+    use super::*;
+
+    #[test]
+    fn create() {
+        let exception = SimpleCommandExceptionType::new("Not enough money");
+        assert_eq!(
+            exception.create().get_type(),
+            &BrigadierError::Custom("Not enough money".to_owned())
+        );
+    }
+
+    #[test]
+    fn create_with_context() {
+        let exception = SimpleCommandExceptionType::new("Not enough money");
+        let mut reader = StringReader::new("buy 64");
+        reader.set_cursor(4);
+        let error = exception.create_with_context(&reader);
+        assert_eq!(error.get_cursor(), Some(4));
+        assert_eq!(error.get_context(), Some("buy <--[HERE]".to_owned()));
+    }
+
+    #[test]
+    fn create_translatable() {
+        let exception = SimpleCommandExceptionType::translatable("shop.money.missing");
+        let error = exception.create();
+        assert_eq!(error.get_type().get_translation_key(), "shop.money.missing");
+        assert_eq!(exception.get_message(), "shop.money.missing");
+
+        let mut catalog = LangMessageCatalog::new();
+        catalog.add_translations(
+            "de_de",
+            HashMap::from([(
+                "shop.money.missing".to_owned(),
+                "Nicht genug Geld".to_owned(),
+            )]),
+        );
+        assert_eq!(
+            catalog.render_error("de_de", error.get_type()),
+            "Nicht genug Geld"
+        );
+    }
+}
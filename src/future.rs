@@ -0,0 +1,25 @@
+use std::{
+    future::Future,
+    pin::{pin, Pin},
+    task::{Context, Poll, Waker},
+};
+
+/// A boxed future, used for async commands, redirect modifiers and suggestion providers so the
+/// crate doesn't depend on any particular runtime. It is `Send` so handlers doing I/O can be
+/// spawned on a multi-threaded runtime.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Polls `future` once, returning its output if it was ready.
+///
+/// The sync methods of the dispatcher share their implementation with the async ones and use
+/// this to run it, since in sync mode it only ever awaits ready futures.
+pub(crate) fn now_or_never<F: Future>(future: F) -> Option<F::Output> {
+    let mut future = pin!(future);
+    match future
+        .as_mut()
+        .poll(&mut Context::from_waker(Waker::noop()))
+    {
+        Poll::Ready(output) => Some(output),
+        Poll::Pending => None,
+    }
+}
@@ -6,8 +6,15 @@ pub mod builder;
 pub mod command;
 pub mod command_dispatcher;
 pub mod context;
+pub mod diagnostic;
 pub mod error;
+pub mod exceptions;
+pub mod future;
+pub mod message_catalog;
+pub mod parse_results;
+pub mod reader_syntax;
 pub mod redirect_modifier;
+pub mod result_consumer;
 pub mod single_redirect_modifier;
 pub mod string_reader;
 pub mod suggestion;
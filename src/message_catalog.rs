@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+#[cfg(feature = "lang-files")]
+use std::{fs, io, path::Path};
+
+use crate::{error::BrigadierError, exceptions::CommandSyntaxException};
+
+/// The English message for every key used by [`BrigadierError`].
+const ENGLISH: &[(&str, &str)] = &[
+    ("parsing.eof", "Unexpected end of input"),
+    ("parsing.expected", "Expected '%s'"),
+    ("parsing.int.expected", "Expected integer"),
+    ("parsing.long.expected", "Expected long"),
+    ("parsing.double.expected", "Expected double"),
+    ("parsing.float.expected", "Expected float"),
+    ("parsing.bool.expected", "Expected bool"),
+    (
+        "parsing.unquoted_string.expected",
+        "Expected unquoted string",
+    ),
+    (
+        "parsing.quote.expected.start",
+        "Expected quote to start a string",
+    ),
+    ("parsing.quote.expected.end", "Unclosed quoted string"),
+    ("parsing.int.invalid", "Invalid integer '%s'"),
+    ("parsing.long.invalid", "Invalid long '%s'"),
+    ("parsing.double.invalid", "Invalid double '%s'"),
+    ("parsing.float.invalid", "Invalid float '%s'"),
+    (
+        "parsing.quote.escape",
+        "Invalid escape sequence '\\%s' in quoted string",
+    ),
+    (
+        "parsing.quote.escape.sequence",
+        "Invalid escape sequence '%s' in quoted string",
+    ),
+    (
+        "parsing.bool.invalid",
+        "Invalid bool, expected true or false but found '%s'",
+    ),
+    (
+        "argument.integer.low",
+        "Integer must not be less than %s, found %s",
+    ),
+    (
+        "argument.integer.big",
+        "Integer must not be more than %s, found %s",
+    ),
+    (
+        "argument.double.low",
+        "Double must not be less than %s, found %s",
+    ),
+    (
+        "argument.double.big",
+        "Double must not be more than %s, found %s",
+    ),
+    (
+        "argument.float.low",
+        "Float must not be less than %s, found %s",
+    ),
+    (
+        "argument.float.big",
+        "Float must not be more than %s, found %s",
+    ),
+    (
+        "argument.long.low",
+        "Long must not be less than %s, found %s",
+    ),
+    (
+        "argument.long.big",
+        "Long must not be more than %s, found %s",
+    ),
+    ("argument.literal.incorrect", "Expected literal %s"),
+    (
+        "argument.unknown",
+        "No such argument '%s' exists on this command",
+    ),
+    ("argument.type_mismatch", "Argument '%s' is not a %s"),
+    ("command.unknown.command", "Unknown command"),
+    ("command.unknown.argument", "Incorrect argument for command"),
+    (
+        "command.expected.separator",
+        "Expected whitespace to end one argument, but found trailing data",
+    ),
+    (
+        "command.requires_async",
+        "Command must be executed asynchronously",
+    ),
+    ("command.custom", "%s"),
+    ("command.context.parse_error", "%s at position %s: %s"),
+];
+
+/// Renders translation keys into messages for a locale, such as `en_us` or `de_de`.
+pub trait MessageCatalog {
+    /// The pattern for `key` in `locale`, e.g. `Invalid integer '%s'`.
+    fn get_pattern(&self, locale: &str, key: &str) -> Option<&str>;
+
+    fn render(&self, locale: &str, key: &str, args: &[String]) -> Option<String> {
+        self.get_pattern(locale, key)
+            .map(|pattern| format_pattern(pattern, args))
+    }
+
+    /// The message for `error` in `locale`, falling back to English.
+    fn render_error(&self, locale: &str, error: &BrigadierError) -> String {
+        self.render(
+            locale,
+            error.get_translation_key(),
+            &error.get_translation_args(),
+        )
+        .unwrap_or_else(|| error.to_string())
+    }
+
+    /// Like [`render_error`](Self::render_error), followed by the position of the error if known.
+    fn render_exception(&self, locale: &str, exception: &CommandSyntaxException) -> String {
+        let message = self.render_error(locale, exception.get_type());
+        match (exception.get_position(), exception.get_context()) {
+            (Some(position), Some(context)) => {
+                let args = [message, position.to_string(), context];
+                self.render(locale, "command.context.parse_error", &args)
+                    .unwrap_or_else(|| {
+                        EnglishMessageCatalog
+                            .render(locale, "command.context.parse_error", &args)
+                            .unwrap_or_default()
+                    })
+            }
+            _ => message,
+        }
+    }
+}
+
+/// The built-in English messages, used for every locale.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnglishMessageCatalog;
+
+impl MessageCatalog for EnglishMessageCatalog {
+    fn get_pattern(&self, _locale: &str, key: &str) -> Option<&str> {
+        english_pattern(key)
+    }
+}
+
+/// Messages loaded from Minecraft-style `lang/<locale>.json` files. Loading them from disk
+/// uses the `lang-files` feature, which is on by default; translations can always be added
+/// directly.
+#[derive(Debug, Clone, Default)]
+pub struct LangMessageCatalog {
+    languages: HashMap<String, HashMap<String, String>>,
+}
+
+impl LangMessageCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `translations` to `locale`, replacing any keys it already has.
+    pub fn add_translations(&mut self, locale: &str, translations: HashMap<String, String>) {
+        self.languages
+            .entry(locale.to_owned())
+            .or_default()
+            .extend(translations);
+    }
+
+    /// Loads a single lang file, using its file name (e.g. `en_us`) as the locale.
+    #[cfg(feature = "lang-files")]
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let locale = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid lang file name"))?;
+
+        let contents = fs::read_to_string(path)?;
+        let translations: HashMap<String, String> = serde_json::from_str(&contents)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        self.add_translations(&locale.to_lowercase(), translations);
+        Ok(())
+    }
+
+    /// Loads every `.json` file in `dir`.
+    #[cfg(feature = "lang-files")]
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                self.load_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_locales(&self) -> impl Iterator<Item = &str> {
+        self.languages.keys().map(String::as_str)
+    }
+}
+
+impl MessageCatalog for LangMessageCatalog {
+    fn get_pattern(&self, locale: &str, key: &str) -> Option<&str> {
+        self.languages
+            .get(&locale.to_lowercase())?
+            .get(key)
+            .map(String::as_str)
+    }
+}
+
+pub(crate) fn english_pattern(key: &str) -> Option<&'static str> {
+    ENGLISH
+        .iter()
+        .find(|(english_key, _)| *english_key == key)
+        .map(|(_, pattern)| *pattern)
+}
+
+/// Fills in `%s`, `%1$s` and `%%` the way Minecraft's lang files use them.
+pub fn format_pattern(pattern: &str, args: &[String]) -> String {
+    let mut result = String::with_capacity(pattern.len());
+    let mut next = 0;
+    let mut rest = pattern;
+
+    while let Some(index) = rest.find('%') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index + 1..];
+
+        if let Some(after) = rest.strip_prefix('%') {
+            result.push('%');
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('s') {
+            result.push_str(args.get(next).map_or("", String::as_str));
+            next += 1;
+            rest = after;
+        } else {
+            let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            match (
+                rest[..digits].parse::<usize>(),
+                rest[digits..].strip_prefix("$s"),
+            ) {
+                (Ok(position), Some(after)) if position > 0 => {
+                    result.push_str(args.get(position - 1).map_or("", String::as_str));
+                    rest = after;
+                }
+                _ => result.push('%'),
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn format_sequential() {
+        assert_eq!(
+            format_pattern("%s must be %s", &args(&["a", "b"])),
+            "a must be b"
+        );
+    }
+
+    #[test]
+    fn format_positional() {
+        assert_eq!(
+            format_pattern("%2$s before %1$s, 100%%", &args(&["a", "b"])),
+            "b before a, 100%"
+        );
+    }
+
+    #[test]
+    fn every_error_key_has_english() {
+        let examples: Vec<_> = BrigadierError::examples()
+            .into_iter()
+            .filter(|error| !matches!(error, BrigadierError::Translatable { .. }))
+            .collect();
+        let keys: std::collections::HashSet<_> = examples
+            .iter()
+            .map(BrigadierError::get_translation_key)
+            .collect();
+        assert_eq!(keys.len(), examples.len());
+        for key in keys {
+            assert!(
+                EnglishMessageCatalog.get_pattern("en_us", key).is_some(),
+                "{key}"
+            );
+        }
+    }
+
+    #[test]
+    fn render_english() {
+        let error = BrigadierError::IntegerTooLow { result: -5, min: 0 };
+        assert_eq!(error.get_translation_key(), "argument.integer.low");
+        assert_eq!(
+            EnglishMessageCatalog.render_error("de_de", &error),
+            "Integer must not be less than 0, found -5"
+        );
+    }
+
+    #[test]
+    fn render_translated() {
+        let mut catalog = LangMessageCatalog::new();
+        catalog.add_translations(
+            "de_de",
+            HashMap::from([(
+                "argument.integer.low".to_owned(),
+                "Die Ganzzahl darf nicht kleiner als %s sein, %s gefunden".to_owned(),
+            )]),
+        );
+
+        let error = BrigadierError::IntegerTooLow { result: -5, min: 0 };
+        assert_eq!(
+            catalog.render_error("de_DE", &error),
+            "Die Ganzzahl darf nicht kleiner als 0 sein, -5 gefunden"
+        );
+        assert_eq!(
+            catalog.render_error("fr_fr", &error),
+            "Integer must not be less than 0, found -5"
+        );
+    }
+
+    #[test]
+    fn render_exception() {
+        let exception =
+            CommandSyntaxException::with_context(BrigadierError::ExpectedInt, "give 64", 5);
+        assert_eq!(
+            EnglishMessageCatalog.render_exception("en_us", &exception),
+            "Expected integer at position 5: give <--[HERE]"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lang-files")]
+    fn load_dir() {
+        let dir = std::env::temp_dir().join(format!("brigadier-lang-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("nl_nl.json"),
+            r#"{"parsing.int.expected": "Geheel getal verwacht"}"#,
+        )
+        .unwrap();
+        fs::write(dir.join("readme.txt"), "not a lang file").unwrap();
+
+        let mut catalog = LangMessageCatalog::new();
+        let loaded = catalog.load_dir(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        loaded.unwrap();
+
+        assert_eq!(catalog.get_locales().collect::<Vec<_>>(), vec!["nl_nl"]);
+        assert_eq!(
+            catalog.render_error("nl_nl", &BrigadierError::ExpectedInt),
+            "Geheel getal verwacht"
+        );
+    }
+}
@@ -1,13 +1,24 @@
-pub struct ParseResults<S> {
-    context: CommandContextBuilder<S>,
-    reader: StringReader,
+use crate::{
+    context::command_context_builder::CommandContextBuilder, exceptions::CommandSyntaxException,
+    string_reader::StringReader, tree::node_id::NodeId,
+};
+
+pub struct ParseResults<'a, S> {
+    pub(crate) context: CommandContextBuilder<'a, S>,
+    pub(crate) reader: StringReader,
+    pub(crate) exceptions: Vec<(NodeId, CommandSyntaxException)>,
 }
 
-impl<S> ParseResults<S> {
-    pub fn new(context: CommandContextBuilder<S>, string_reader: StringReader) -> ParseResults<S> {
+impl<'a, S> ParseResults<'a, S> {
+    pub fn new(
+        context: CommandContextBuilder<'a, S>,
+        string_reader: StringReader,
+        exceptions: Vec<(NodeId, CommandSyntaxException)>,
+    ) -> ParseResults<'a, S> {
         ParseResults {
             context,
             reader: string_reader,
+            exceptions,
         }
     }
 
@@ -15,7 +26,12 @@ impl<S> ParseResults<S> {
         &self.reader
     }
 
-    pub fn get_context(&self) -> &CommandContextBuilder<S> {
+    pub fn get_context(&self) -> &CommandContextBuilder<'a, S> {
         &self.context
     }
+
+    /// The errors raised by each node that was tried at the point where parsing stopped.
+    pub fn get_exceptions(&self) -> &[(NodeId, CommandSyntaxException)] {
+        &self.exceptions
+    }
 }
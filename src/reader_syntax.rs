@@ -0,0 +1,263 @@
+const SYNTAX_ESCAPE: char = '\\';
+const SYNTAX_DOUBLE_QUOTE: char = '"';
+const SYNTAX_SINGLE_QUOTE: char = '\'';
+const SYNTAX_UNQUOTED: [char; 4] = ['_', '-', '.', '+'];
+/// The characters that may follow the escape character when rich escapes are enabled.
+pub(crate) const RICH_ESCAPES: [char; 6] = ['n', 't', 'r', '0', 'x', 'u'];
+
+/// The characters a [`StringReader`](crate::string_reader::StringReader) treats specially. The
+/// default matches Minecraft: unquoted strings are letters, digits and `_-.+`, strings are quoted
+/// with `"` or `'`, and `\` escapes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReaderSyntax {
+    unquoted: Vec<char>,
+    quotes: Vec<char>,
+    escape: char,
+    whitespace: Option<Vec<char>>,
+    extended_numbers: bool,
+    rich_escapes: bool,
+}
+
+impl ReaderSyntax {
+    pub fn new() -> Self {
+        Self {
+            unquoted: SYNTAX_UNQUOTED.to_vec(),
+            quotes: vec![SYNTAX_DOUBLE_QUOTE, SYNTAX_SINGLE_QUOTE],
+            escape: SYNTAX_ESCAPE,
+            whitespace: None,
+            extended_numbers: false,
+            rich_escapes: false,
+        }
+    }
+
+    /// The characters besides letters and digits that unquoted strings may contain, replacing
+    /// the default `_-.+`.
+    pub fn with_unquoted_chars(mut self, chars: impl IntoIterator<Item = char>) -> Self {
+        self.unquoted = chars.into_iter().collect();
+        self
+    }
+
+    /// Also allows `chars` in unquoted strings, e.g. `:` and `/` for resource paths.
+    pub fn allow_unquoted(mut self, chars: impl IntoIterator<Item = char>) -> Self {
+        self.unquoted.extend(chars);
+        self
+    }
+
+    /// The characters that start and end a quoted string.
+    pub fn with_quotes(mut self, quotes: impl IntoIterator<Item = char>) -> Self {
+        self.quotes = quotes.into_iter().collect();
+        self
+    }
+
+    pub fn with_escape(mut self, escape: char) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// The characters skipped as whitespace, instead of everything Unicode considers whitespace.
+    pub fn with_whitespace(mut self, whitespace: impl IntoIterator<Item = char>) -> Self {
+        self.whitespace = Some(whitespace.into_iter().collect());
+        self
+    }
+
+    /// Whether numbers may also be written as hex (`0xFF`) or binary (`0b1010`), with `_`
+    /// separators, a leading `+`, or in scientific notation (`1.5e3`). Off by default.
+    pub fn with_extended_numbers(mut self, extended_numbers: bool) -> Self {
+        self.extended_numbers = extended_numbers;
+        self
+    }
+
+    /// Whether quoted strings may also contain `\n`, `\t`, `\r`, `\0`, `\xNN` and `\u{XXXX}`
+    /// escapes. Off by default, where only the escape character and the quote can be escaped.
+    pub fn with_rich_escapes(mut self, rich_escapes: bool) -> Self {
+        self.rich_escapes = rich_escapes;
+        self
+    }
+
+    pub fn is_allowed_in_unquoted_string(&self, c: char) -> bool {
+        c.is_alphanumeric() || self.unquoted.contains(&c)
+    }
+
+    pub fn is_quote(&self, c: char) -> bool {
+        self.quotes.contains(&c)
+    }
+
+    pub fn get_escape(&self) -> char {
+        self.escape
+    }
+
+    pub fn is_whitespace(&self, c: char) -> bool {
+        match &self.whitespace {
+            Some(whitespace) => whitespace.contains(&c),
+            None => c.is_whitespace(),
+        }
+    }
+
+    pub fn has_extended_numbers(&self) -> bool {
+        self.extended_numbers
+    }
+
+    pub fn has_rich_escapes(&self) -> bool {
+        self.rich_escapes
+    }
+
+    /// This syntax's characters, with every number and escape mode that is enabled in either
+    /// this syntax or `other`.
+    pub fn with_modes_of(&self, other: &ReaderSyntax) -> ReaderSyntax {
+        self.clone()
+            .with_extended_numbers(self.extended_numbers || other.extended_numbers)
+            .with_rich_escapes(self.rich_escapes || other.rich_escapes)
+    }
+
+    /// `input` as a quoted string that reads back as `input` with this syntax. With rich escapes,
+    /// line breaks and other control characters are escaped too.
+    ///
+    /// Returns `None` if this syntax can't read such a string back: when it has no quote
+    /// characters, or its escape character is also a quote or, with rich escapes, one of the
+    /// letters that start an escape sequence.
+    pub fn escape(&self, input: &str) -> Option<String> {
+        let quote = *self.quotes.first()?;
+        if self.is_quote(self.escape) || (self.rich_escapes && RICH_ESCAPES.contains(&self.escape))
+        {
+            return None;
+        }
+
+        let mut result = String::with_capacity(input.len() + 2);
+        result.push(quote);
+        for c in input.chars() {
+            if c == quote || c == self.escape {
+                result.push(self.escape);
+                result.push(c);
+            } else if self.rich_escapes && c.is_control() {
+                result.push(self.escape);
+                match c {
+                    '\n' => result.push('n'),
+                    '\t' => result.push('t'),
+                    '\r' => result.push('r'),
+                    '\0' => result.push('0'),
+                    _ => result.push_str(&format!("u{{{:x}}}", c as u32)),
+                }
+            } else {
+                result.push(c);
+            }
+        }
+        result.push(quote);
+        Some(result)
+    }
+
+    /// `input` as is if it can be read as an unquoted string, or [escaped](Self::escape) if not.
+    pub fn escape_if_required(&self, input: &str) -> Option<String> {
+        if !input.is_empty()
+            && input
+                .chars()
+                .all(|c| self.is_allowed_in_unquoted_string(c) && !self.is_quote(c))
+        {
+            Some(input.to_owned())
+        } else {
+            self.escape(input)
+        }
+    }
+}
+
+impl Default for ReaderSyntax {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_syntax() {
+        let syntax = ReaderSyntax::new();
+        assert!(syntax.is_allowed_in_unquoted_string('a'));
+        assert!(syntax.is_allowed_in_unquoted_string('+'));
+        assert!(!syntax.is_allowed_in_unquoted_string(':'));
+        assert!(syntax.is_quote('"'));
+        assert!(syntax.is_quote('\''));
+        assert_eq!(syntax.get_escape(), '\\');
+        assert!(syntax.is_whitespace('\t'));
+    }
+
+    #[test]
+    fn allow_unquoted() {
+        let syntax = ReaderSyntax::new().allow_unquoted([':', '/']);
+        assert!(syntax.is_allowed_in_unquoted_string(':'));
+        assert!(syntax.is_allowed_in_unquoted_string('/'));
+        assert!(syntax.is_allowed_in_unquoted_string('_'));
+    }
+
+    #[test]
+    fn with_unquoted_chars() {
+        let syntax = ReaderSyntax::new().with_unquoted_chars(['#']);
+        assert!(syntax.is_allowed_in_unquoted_string('#'));
+        assert!(syntax.is_allowed_in_unquoted_string('7'));
+        assert!(!syntax.is_allowed_in_unquoted_string('_'));
+    }
+
+    #[test]
+    fn with_whitespace() {
+        let syntax = ReaderSyntax::new().with_whitespace([' ']);
+        assert!(syntax.is_whitespace(' '));
+        assert!(!syntax.is_whitespace('\t'));
+    }
+
+    #[test]
+    fn with_modes_of() {
+        let syntax = ReaderSyntax::new()
+            .allow_unquoted([':'])
+            .with_modes_of(&ReaderSyntax::new().with_extended_numbers(true));
+        assert!(syntax.is_allowed_in_unquoted_string(':'));
+        assert!(syntax.has_extended_numbers());
+        assert!(!syntax.has_rich_escapes());
+    }
+
+    #[test]
+    fn escape() {
+        let syntax = ReaderSyntax::new();
+        assert_eq!(
+            syntax.escape(r#"say "hi" \o/"#).unwrap(),
+            r#""say \"hi\" \\o/""#
+        );
+        assert_eq!(syntax.escape("a\nb").unwrap(), "\"a\nb\"");
+    }
+
+    #[test]
+    fn escape_rich() {
+        let syntax = ReaderSyntax::new().with_rich_escapes(true);
+        assert_eq!(
+            syntax.escape("a\nb\tc\r\0\u{1b}é").unwrap(),
+            r#""a\nb\tc\r\0\u{1b}é""#
+        );
+    }
+
+    #[test]
+    fn escape_if_required() {
+        let syntax = ReaderSyntax::new();
+        assert_eq!(syntax.escape_if_required("Steve_1.2").unwrap(), "Steve_1.2");
+        assert_eq!(
+            syntax.escape_if_required("two words").unwrap(),
+            "\"two words\""
+        );
+        assert_eq!(syntax.escape_if_required("").unwrap(), "\"\"");
+
+        let syntax = ReaderSyntax::new().allow_unquoted(['"']);
+        assert_eq!(syntax.escape_if_required("\"a").unwrap(), r#""\"a""#);
+    }
+
+    #[test]
+    fn escape_unreadable_syntax() {
+        let no_quotes = ReaderSyntax::new().with_quotes([]);
+        assert_eq!(no_quotes.escape("a b"), None);
+        assert_eq!(no_quotes.escape_if_required("ab").unwrap(), "ab");
+
+        let quote_escape = ReaderSyntax::new().with_escape('\'');
+        assert_eq!(quote_escape.escape("a"), None);
+
+        let letter_escape = ReaderSyntax::new().with_escape('n');
+        assert_eq!(letter_escape.escape("a\nb").unwrap(), "\"a\nb\"");
+        assert_eq!(letter_escape.with_rich_escapes(true).escape("a\nb"), None);
+    }
+}
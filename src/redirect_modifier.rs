@@ -1,5 +1,66 @@
-use crate::{context::command_context::CommandContext, error::BrigadierError};
+use crate::{
+    context::command_context::CommandContext, error::BrigadierError,
+    exceptions::CommandSyntaxException, future::BoxFuture,
+};
 
-pub trait RedirectModifier<S> {
-    fn apply(&self, context: CommandContext<S>) -> Result<Vec<S>, BrigadierError>;
+pub trait RedirectModifier<S>: Send + Sync {
+    fn apply(&self, context: &CommandContext<S>) -> Result<Vec<S>, CommandSyntaxException>;
+
+    /// The sources to continue with when executing asynchronously. Defaults to [`apply`](Self::apply).
+    fn apply_async<'a>(
+        &'a self,
+        context: &'a CommandContext<S>,
+    ) -> BoxFuture<'a, Result<Vec<S>, CommandSyntaxException>>
+    where
+        S: Send + Sync,
+    {
+        Box::pin(std::future::ready(self.apply(context)))
+    }
+}
+
+impl<S, F> RedirectModifier<S> for F
+where
+    F: Fn(&CommandContext<S>) -> Result<Vec<S>, CommandSyntaxException> + Send + Sync,
+{
+    fn apply(&self, context: &CommandContext<S>) -> Result<Vec<S>, CommandSyntaxException> {
+        self(context)
+    }
+}
+
+/// A redirect modifier that returns a future, created with [`async_modifier`]. It can only be
+/// applied by [`CommandDispatcher::execute_async`](crate::command_dispatcher::CommandDispatcher::execute_async).
+pub struct AsyncRedirectModifier<F>(F);
+
+pub fn async_modifier<S, F>(f: F) -> AsyncRedirectModifier<F>
+where
+    F: for<'a, 'b> Fn(
+            &'a CommandContext<'b, S>,
+        ) -> BoxFuture<'a, Result<Vec<S>, CommandSyntaxException>>
+        + Send
+        + Sync,
+{
+    AsyncRedirectModifier(f)
+}
+
+impl<S, F> RedirectModifier<S> for AsyncRedirectModifier<F>
+where
+    F: for<'a, 'b> Fn(
+            &'a CommandContext<'b, S>,
+        ) -> BoxFuture<'a, Result<Vec<S>, CommandSyntaxException>>
+        + Send
+        + Sync,
+{
+    fn apply(&self, _context: &CommandContext<S>) -> Result<Vec<S>, CommandSyntaxException> {
+        Err(BrigadierError::RequiresAsyncExecution.create())
+    }
+
+    fn apply_async<'a>(
+        &'a self,
+        context: &'a CommandContext<S>,
+    ) -> BoxFuture<'a, Result<Vec<S>, CommandSyntaxException>>
+    where
+        S: Send + Sync,
+    {
+        (self.0)(context)
+    }
 }
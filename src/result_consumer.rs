@@ -0,0 +1,14 @@
+use crate::context::command_context::CommandContext;
+
+pub trait ResultConsumer<S>: Send + Sync {
+    fn on_command_complete(&self, context: &CommandContext<S>, success: bool, result: i32);
+}
+
+impl<S, F> ResultConsumer<S> for F
+where
+    F: Fn(&CommandContext<S>, bool, i32) + Send + Sync,
+{
+    fn on_command_complete(&self, context: &CommandContext<S>, success: bool, result: i32) {
+        self(context, success, result)
+    }
+}
@@ -1,5 +1,14 @@
-use crate::{context::command_context::CommandContext, error::BrigadierError};
+use crate::{context::command_context::CommandContext, exceptions::CommandSyntaxException};
 
-pub trait SingleRedirectModifier<S> {
-    fn apply(&self, context: CommandContext<S>) -> Result<S, BrigadierError>;
+pub trait SingleRedirectModifier<S>: Send + Sync {
+    fn apply(&self, context: &CommandContext<S>) -> Result<S, CommandSyntaxException>;
+}
+
+impl<S, F> SingleRedirectModifier<S> for F
+where
+    F: Fn(&CommandContext<S>) -> Result<S, CommandSyntaxException> + Send + Sync,
+{
+    fn apply(&self, context: &CommandContext<S>) -> Result<S, CommandSyntaxException> {
+        self(context)
+    }
 }
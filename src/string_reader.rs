@@ -1,32 +1,68 @@
-use crate::error::BrigadierError;
-
-const SYNTAX_ESCAPE: char = '\\';
-const SYNTAX_DOUBLE_QUOTE: char = '"';
-const SYNTAX_SINGLE_QUOTE: char = '\'';
-
-pub struct StringReader<'a> {
-    pub string: &'a str,
+use std::sync::Arc;
+
+use crate::{
+    error::BrigadierError,
+    exceptions::CommandSyntaxException,
+    reader_syntax::{ReaderSyntax, RICH_ESCAPES},
+};
+
+/// A cursor over a command. The input is shared, so cloning a reader is cheap and it doesn't
+/// borrow from the buffer it was created from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringReader {
+    string: Arc<str>,
     cursor: usize,
+    syntax: Arc<ReaderSyntax>,
 }
 
-impl<'a> StringReader<'a> {
-    pub fn new(string: &str) -> StringReader {
-        StringReader { string, cursor: 0 }
+impl StringReader {
+    pub fn new(string: impl Into<Arc<str>>) -> StringReader {
+        StringReader {
+            string: string.into(),
+            cursor: 0,
+            syntax: Arc::default(),
+        }
+    }
+
+    pub fn with_syntax(mut self, syntax: impl Into<Arc<ReaderSyntax>>) -> Self {
+        self.syntax = syntax.into();
+        self
+    }
+
+    pub fn get_syntax(&self) -> &ReaderSyntax {
+        &self.syntax
+    }
+
+    /// See [`ReaderSyntax::with_extended_numbers`].
+    pub fn with_extended_numbers(self, extended_numbers: bool) -> Self {
+        let syntax = self.get_syntax().clone();
+        self.with_syntax(syntax.with_extended_numbers(extended_numbers))
+    }
+
+    pub fn has_extended_numbers(&self) -> bool {
+        self.syntax.has_extended_numbers()
     }
 
     pub fn get_string(&self) -> &str {
-        // owned / clone string ?
-        self.string
+        &self.string
+    }
+
+    /// The input as a shared string, which can outlive this reader.
+    pub fn get_shared_string(&self) -> Arc<str> {
+        self.string.clone()
     }
 
     pub fn set_cursor(&mut self, cursor: usize) {
         self.cursor = cursor;
     }
 
+    /// The number of bytes after the cursor. Like the cursor, lengths count bytes, while
+    /// [`can_read`](Self::can_read) and [`peek`](Self::peek) count characters.
     pub fn get_remaining_length(&self) -> usize {
         self.string.len() - self.cursor
     }
 
+    /// The length of the input in bytes.
     pub fn get_total_length(&self) -> usize {
         self.string.len()
     }
@@ -36,154 +72,199 @@ impl<'a> StringReader<'a> {
     }
 
     pub fn get_read(&self) -> &str {
-        &self.string[..self.cursor]
+        &self.string[..self.string.floor_char_boundary(self.cursor)]
     }
 
     pub fn get_remaining(&self) -> &str {
-        &self.string[self.cursor..]
+        &self.string[self.string.floor_char_boundary(self.cursor)..]
     }
 
+    /// Whether `length` more characters can be read. Like [`peek`](Self::peek), this counts
+    /// characters rather than bytes, so `can_read(2)` means `peek(1)` will succeed.
     pub fn can_read(&self, length: usize) -> bool {
-        self.cursor + length <= self.string.len()
+        match length {
+            0 => self.cursor <= self.string.len(),
+            // the common case, which needs no walk over the characters
+            1 => self.cursor < self.string.len(),
+            _ => self.get_remaining().chars().nth(length - 1).is_some(),
+        }
     }
 
     pub fn can_read_char(&self) -> bool {
         self.can_read(1)
     }
 
-    pub fn peek(&self, offset: usize) -> Result<char, BrigadierError> {
-        if self.can_read(offset + 1) {
-            Ok(self.string.chars().nth(self.cursor + offset).unwrap())
-        } else {
-            Err(BrigadierError::EOF)
-        }
+    /// The character `offset` characters after the cursor.
+    pub fn peek(&self, offset: usize) -> Result<char, CommandSyntaxException> {
+        self.get_remaining()
+            .chars()
+            .nth(offset)
+            .ok_or_else(|| BrigadierError::EOF.create_with_context(self))
     }
 
-    pub fn peek_char(&self) -> Result<char, BrigadierError> {
+    pub fn peek_char(&self) -> Result<char, CommandSyntaxException> {
         self.peek(0)
     }
 
-    pub fn read(&mut self) -> Result<char, BrigadierError> {
-        if self.can_read_char() {
-            let c = self.string.chars().nth(self.cursor).unwrap();
-            self.cursor += c.len_utf8();
-            Ok(c)
-        } else {
-            Err(BrigadierError::EOF)
-        }
+    pub fn read(&mut self) -> Result<char, CommandSyntaxException> {
+        let c = self.peek_char()?;
+        self.cursor += c.len_utf8();
+        Ok(c)
     }
 
+    /// Moves the cursor past the next character, however many bytes it takes up.
     pub fn skip(&mut self) {
-        self.cursor += 1;
+        self.cursor += self.peek_char().map_or(1, char::len_utf8);
     }
 
+    /// Whether `c` can be part of a plain number. Extended numbers are scanned by their grammar
+    /// instead, since whether a letter belongs to them depends on where it appears.
     pub fn is_allowed_number(&self, c: char) -> bool {
-        c.is_numeric() || c == '-' || c == '.'
+        c.is_ascii_digit() || c == '-' || c == '.'
     }
 
     pub fn is_quoted_string_start(&self, c: char) -> bool {
-        c == SYNTAX_DOUBLE_QUOTE || c == SYNTAX_SINGLE_QUOTE
+        self.syntax.is_quote(c)
     }
 
     pub fn skip_whitespace(&mut self) {
         while self.can_read_char() {
             let c = self.peek_char().unwrap();
-            if !c.is_whitespace() {
+            if !self.syntax.is_whitespace(c) {
                 break;
             }
             self.skip();
         }
     }
 
-    pub fn read_int(&mut self) -> Result<i32, BrigadierError> {
+    pub fn read_int(&mut self) -> Result<i32, CommandSyntaxException> {
+        let extended = self.has_extended_numbers();
+        self.read_number(
+            BrigadierError::ExpectedInt,
+            BrigadierError::InvalidInt,
+            |number| {
+                if extended {
+                    parse_extended_integer(number)?.try_into().ok()
+                } else {
+                    number.parse().ok()
+                }
+            },
+        )
+    }
+
+    pub fn read_long(&mut self) -> Result<i64, CommandSyntaxException> {
+        let extended = self.has_extended_numbers();
+        self.read_number(
+            BrigadierError::ExpectedLong,
+            BrigadierError::InvalidLong,
+            |number| {
+                if extended {
+                    parse_extended_integer(number)?.try_into().ok()
+                } else {
+                    number.parse().ok()
+                }
+            },
+        )
+    }
+
+    pub fn read_double(&mut self) -> Result<f64, CommandSyntaxException> {
+        let extended = self.has_extended_numbers();
+        self.read_number(
+            BrigadierError::ExpectedDouble,
+            BrigadierError::InvalidDouble,
+            |number| parse_float(number, extended).filter(|number: &f64| number.is_finite()),
+        )
+    }
+
+    pub fn read_float(&mut self) -> Result<f32, CommandSyntaxException> {
+        let extended = self.has_extended_numbers();
+        self.read_number(
+            BrigadierError::ExpectedFloat,
+            BrigadierError::InvalidFloat,
+            |number| parse_float(number, extended).filter(|number: &f32| number.is_finite()),
+        )
+    }
+
+    /// Reads the characters allowed in a number and parses them. If they don't parse, the cursor
+    /// is left at the start of the literal and the error's range covers all of it.
+    fn read_number<T>(
+        &mut self,
+        expected: BrigadierError,
+        invalid: fn(String) -> BrigadierError,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<T, CommandSyntaxException> {
         let start = self.cursor;
 
-        while self.can_read_char() && self.is_allowed_number(self.peek_char()?) {
-            self.skip();
-        }
-
-        let number_str = &self.string[start..self.cursor];
-        if number_str.is_empty() {
-            Err(BrigadierError::ExpectedInt)
+        if self.has_extended_numbers() {
+            self.skip_extended_number();
         } else {
-            match number_str.parse::<i32>() {
-                Ok(number) => Ok(number),
-                Err(_) => {
-                    self.cursor = start;
-                    Err(BrigadierError::InvalidInt)
-                }
+            while self.can_read_char() && self.is_allowed_number(self.peek_char()?) {
+                self.skip();
             }
         }
-    }
-
-    pub fn read_long(&mut self) -> Result<i64, BrigadierError> {
-        let start = self.cursor;
-
-        while self.can_read_char() && self.is_allowed_number(self.peek_char()?) {
-            self.skip();
-        }
 
         let number_str = &self.string[start..self.cursor];
         if number_str.is_empty() {
-            Err(BrigadierError::ExpectedLong)
-        } else {
-            match number_str.parse::<i64>() {
-                Ok(number) => Ok(number),
-                Err(_) => {
-                    self.cursor = start;
-                    Err(BrigadierError::InvalidLong)
-                }
-            }
-        }
-    }
-
-    pub fn read_double(&mut self) -> Result<f64, BrigadierError> {
-        let start = self.cursor;
-
-        while self.can_read_char() && self.is_allowed_number(self.peek_char()?) {
-            self.skip();
+            return Err(expected.create_with_context(self));
         }
 
-        let number_str = &self.string[start..self.cursor];
-        if number_str.is_empty() {
-            Err(BrigadierError::ExpectedDouble)
-        } else {
-            match number_str.parse::<f64>() {
-                Ok(number) => Ok(number),
-                Err(_) => {
-                    self.cursor = start;
-                    Err(BrigadierError::InvalidDouble)
-                }
+        match parse(number_str) {
+            Some(number) => Ok(number),
+            None => {
+                let number = number_str.to_owned();
+                let end = self.cursor;
+                self.cursor = start;
+                Err(invalid(number).create_with_context(self).with_end(end))
             }
         }
     }
 
-    pub fn read_float(&mut self) -> Result<f32, BrigadierError> {
-        let start = self.cursor;
-
-        while self.can_read_char() && self.is_allowed_number(self.peek_char()?) {
-            self.skip();
-        }
-
-        let number_str = &self.string[start..self.cursor];
-        if number_str.is_empty() {
-            Err(BrigadierError::ExpectedFloat)
+    /// Moves past an extended number, only taking letters where the grammar allows them: a
+    /// `0x`/`0b` prefix, hex digits after `0x`, and an exponent followed by a digit. Anything
+    /// else, like the `s` in `10s`, is left for the caller.
+    fn skip_extended_number(&mut self) {
+        let bytes = self.string[self.cursor..].as_bytes();
+        let mut i = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+
+        if bytes.get(i) == Some(&b'0')
+            && matches!(bytes.get(i + 1), Some(b'x' | b'X' | b'b' | b'B'))
+        {
+            let hex = matches!(bytes[i + 1], b'x' | b'X');
+            i += 2;
+            while bytes.get(i).is_some_and(|&b| {
+                b == b'_'
+                    || if hex {
+                        b.is_ascii_hexdigit()
+                    } else {
+                        b.is_ascii_digit()
+                    }
+            }) {
+                i += 1;
+            }
         } else {
-            match number_str.parse::<f32>() {
-                Ok(number) => Ok(number),
-                Err(_) => {
-                    self.cursor = start;
-                    Err(BrigadierError::InvalidFloat)
+            while let Some(&b) = bytes.get(i) {
+                if b.is_ascii_digit() || matches!(b, b'_' | b'.' | b'-') {
+                    i += 1;
+                } else if matches!(b, b'e' | b'E') {
+                    let digit = i + 1 + usize::from(matches!(bytes.get(i + 1), Some(b'+' | b'-')));
+                    if !bytes.get(digit).is_some_and(u8::is_ascii_digit) {
+                        break;
+                    }
+                    i = digit + 1;
+                } else {
+                    break;
                 }
             }
         }
+
+        self.cursor += i;
     }
 
     pub fn is_allowed_in_unquoted_string(&self, c: char) -> bool {
-        c.is_alphanumeric() || c == '_' || c == '-' || c == '.' || c == '+'
+        self.syntax.is_allowed_in_unquoted_string(c)
     }
 
-    pub fn read_unqoted_string(&mut self) -> Result<String, BrigadierError> {
+    pub fn read_unqoted_string(&mut self) -> Result<String, CommandSyntaxException> {
         let start = self.cursor;
 
         while self.can_read_char() && self.is_allowed_in_unquoted_string(self.peek_char()?) {
@@ -194,36 +275,55 @@ impl<'a> StringReader<'a> {
         Ok(string.to_string())
     }
 
-    pub fn read_quoted_string(&mut self) -> Result<String, BrigadierError> {
+    pub fn read_quoted_string(&mut self) -> Result<String, CommandSyntaxException> {
         if !self.can_read_char() {
             return Ok(String::new());
         }
 
         let next = self.peek_char()?;
         if !self.is_quoted_string_start(next) {
-            return Err(BrigadierError::ExpectedStartOfQuote);
+            return Err(BrigadierError::ExpectedStartOfQuote.create_with_context(self));
         }
 
         self.skip();
 
-        return self.read_string_until(next);
+        self.read_string_until(next)
     }
 
-    pub fn read_string_until(&mut self, terminator: char) -> Result<String, BrigadierError> {
+    pub fn read_string_until(
+        &mut self,
+        terminator: char,
+    ) -> Result<String, CommandSyntaxException> {
+        let escape = self.syntax.get_escape();
+        let rich_escapes = self.syntax.has_rich_escapes();
         let mut result = String::new();
         let mut escaped = false;
 
         while self.can_read_char() {
             let c = self.read()?;
             if escaped {
-                if c == terminator || c == SYNTAX_ESCAPE {
+                if c == terminator || c == escape {
                     result.push(c);
                     escaped = false;
+                } else if rich_escapes && RICH_ESCAPES.contains(&c) {
+                    let start = self.get_cursor() - c.len_utf8() - escape.len_utf8();
+                    result.push(self.read_rich_escape(start, c)?);
+                    escaped = false;
+                } else if escape == '\\' {
+                    self.set_cursor(self.get_cursor() - c.len_utf8());
+                    return Err(BrigadierError::InvalidEscape(c).create_with_context(self));
                 } else {
-                    self.set_cursor(self.get_cursor() - 1);
-                    return Err(BrigadierError::InvalidEscape(c));
+                    // `parsing.quote.escape` assumes a backslash, so report the whole sequence.
+                    let end = self.get_cursor();
+                    let start = end - c.len_utf8() - escape.len_utf8();
+                    self.set_cursor(start);
+                    return Err(
+                        BrigadierError::InvalidEscapeSequence(format!("{escape}{c}"))
+                            .create_with_context(self)
+                            .with_end(end),
+                    );
                 }
-            } else if c == SYNTAX_ESCAPE {
+            } else if c == escape {
                 escaped = true;
             } else if c == terminator {
                 return Ok(result);
@@ -232,10 +332,71 @@ impl<'a> StringReader<'a> {
             }
         }
 
-        Err(BrigadierError::ExpectedEndOfQuote)
+        Err(BrigadierError::ExpectedEndOfQuote.create_with_context(self))
+    }
+
+    /// Reads the rest of a rich escape whose escape character is at `start` and whose kind has
+    /// just been read. Malformed sequences fail at `start` with the whole sequence as the range.
+    fn read_rich_escape(
+        &mut self,
+        start: usize,
+        kind: char,
+    ) -> Result<char, CommandSyntaxException> {
+        let value = match kind {
+            'n' => return Ok('\n'),
+            't' => return Ok('\t'),
+            'r' => return Ok('\r'),
+            '0' => return Ok('\0'),
+            'x' => {
+                let digits = self.read_hex_digits(2);
+                (digits.len() == 2)
+                    .then(|| u32::from_str_radix(&digits, 16).ok())
+                    .flatten()
+            }
+            _ => {
+                let open = self.can_read_char() && self.peek_char()? == '{';
+                if open {
+                    self.skip();
+                }
+                let digits = self.read_hex_digits(6);
+                let close = self.can_read_char() && self.peek_char()? == '}';
+                if close {
+                    self.skip();
+                }
+                (open && close && !digits.is_empty())
+                    .then(|| u32::from_str_radix(&digits, 16).ok())
+                    .flatten()
+            }
+        };
+
+        match value.and_then(char::from_u32) {
+            Some(c) => Ok(c),
+            None => {
+                let end = self.get_cursor();
+                let sequence = self.string[start..end].to_owned();
+                self.set_cursor(start);
+                Err(BrigadierError::InvalidEscapeSequence(sequence)
+                    .create_with_context(self)
+                    .with_end(end))
+            }
+        }
     }
 
-    pub fn read_string(&mut self) -> Result<String, BrigadierError> {
+    fn read_hex_digits(&mut self, max: usize) -> String {
+        let mut digits = String::new();
+        while digits.len() < max && self.can_read_char() {
+            match self.peek_char() {
+                Ok(c) if c.is_ascii_hexdigit() => {
+                    digits.push(c);
+                    self.skip();
+                }
+                _ => break,
+            }
+        }
+        digits
+    }
+
+    pub fn read_string(&mut self) -> Result<String, CommandSyntaxException> {
         if !self.can_read_char() {
             return Ok(String::new());
         }
@@ -249,31 +410,31 @@ impl<'a> StringReader<'a> {
         self.read_unqoted_string()
     }
 
-    pub fn read_boolean(&mut self) -> Result<bool, BrigadierError> {
+    pub fn read_boolean(&mut self) -> Result<bool, CommandSyntaxException> {
         let start = self.cursor;
         let value: String = self.read_string()?;
         if value.is_empty() {
-            Err(BrigadierError::ExpectedBool)
+            Err(BrigadierError::ExpectedBool.create_with_context(self))
         } else {
             match value.as_str() {
                 "true" => Ok(true),
                 "false" => Ok(false),
                 _ => {
                     self.cursor = start;
-                    Err(BrigadierError::InvalidBool)
+                    Err(BrigadierError::InvalidBool(value).create_with_context(self))
                 }
             }
         }
     }
 
-    pub fn expect(&mut self, c: char) -> Result<(), BrigadierError> {
+    pub fn expect(&mut self, c: char) -> Result<(), CommandSyntaxException> {
         if !self.can_read_char() {
-            return Err(BrigadierError::ExpectedSymbol(c));
+            return Err(BrigadierError::ExpectedSymbol(c).create_with_context(self));
         }
 
         let next = self.peek_char()?;
         if next != c {
-            return Err(BrigadierError::ExpectedSymbol(c));
+            return Err(BrigadierError::ExpectedSymbol(c).create_with_context(self));
         }
 
         self.skip();
@@ -281,26 +442,100 @@ impl<'a> StringReader<'a> {
     }
 }
 
-impl<'a> Into<StringReader<'a>> for &'a str {
-    fn into(self) -> StringReader<'a> {
-        StringReader::new(self)
+/// Parses an integer with an optional sign, `0x`/`0b` prefix and `_` separators.
+fn parse_extended_integer(number: &str) -> Option<i128> {
+    let (negative, unsigned) = match number.as_bytes().first() {
+        Some(b'-') => (true, &number[1..]),
+        Some(b'+') => (false, &number[1..]),
+        _ => (false, number),
+    };
+
+    let (radix, digits) = if let Some(digits) = strip_prefix_ignore_case(unsigned, "0x") {
+        (16, digits)
+    } else if let Some(digits) = strip_prefix_ignore_case(unsigned, "0b") {
+        (2, digits)
+    } else {
+        (10, unsigned)
+    };
+
+    if !has_valid_separators(digits, radix)
+        || !digits.chars().all(|c| c == '_' || c.is_digit(radix))
+    {
+        return None;
+    }
+
+    let value = i128::from_str_radix(&digits.replace('_', ""), radix).ok()?;
+    Some(if negative { -value } else { value })
+}
+
+fn parse_float<T: std::str::FromStr>(number: &str, extended: bool) -> Option<T> {
+    if !extended {
+        return number.parse().ok();
+    }
+
+    if !has_valid_separators(number, 10)
+        || !number
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '_' | '.' | 'e' | 'E' | '+' | '-'))
+    {
+        return None;
+    }
+    number.replace('_', "").parse().ok()
+}
+
+fn strip_prefix_ignore_case<'a>(string: &'a str, prefix: &str) -> Option<&'a str> {
+    string
+        .get(..prefix.len())
+        .filter(|start| start.eq_ignore_ascii_case(prefix))
+        .map(|_| &string[prefix.len()..])
+}
+
+/// Whether every `_` in `number` sits between two digits of `radix`, so `1_e5` and `1e_5` are
+/// rejected as decimals.
+fn has_valid_separators(number: &str, radix: u32) -> bool {
+    let is_digit = |b: &u8| char::from(*b).is_digit(radix);
+    let bytes = number.as_bytes();
+    bytes.iter().enumerate().all(|(index, &b)| {
+        b != b'_'
+            || (index > 0
+                && is_digit(&bytes[index - 1])
+                && bytes.get(index + 1).is_some_and(is_digit))
+    })
+}
+
+impl From<&str> for StringReader {
+    fn from(string: &str) -> Self {
+        StringReader::new(string)
+    }
+}
+
+impl From<String> for StringReader {
+    fn from(string: String) -> Self {
+        StringReader::new(string)
+    }
+}
+
+impl From<Arc<str>> for StringReader {
+    fn from(string: Arc<str>) -> Self {
+        StringReader::new(string)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::context::string_range::StringRange;
 
     #[test]
     fn can_read() {
         let mut reader = StringReader::new("abc");
-        assert_eq!(reader.can_read_char(), true);
+        assert!(reader.can_read_char());
         reader.skip(); // a
-        assert_eq!(reader.can_read_char(), true);
+        assert!(reader.can_read_char());
         reader.skip(); // b
-        assert_eq!(reader.can_read_char(), true);
+        assert!(reader.can_read_char());
         reader.skip(); // c
-        assert_eq!(reader.can_read_char(), false);
+        assert!(!reader.can_read_char());
     }
 
     #[test]
@@ -318,11 +553,11 @@ mod test {
     #[test]
     fn can_read_length() {
         let reader = StringReader::new("abc");
-        assert_eq!(reader.can_read(1), true);
-        assert_eq!(reader.can_read(2), true);
-        assert_eq!(reader.can_read(3), true);
-        assert_eq!(reader.can_read(4), false);
-        assert_eq!(reader.can_read(5), false);
+        assert!(reader.can_read(1));
+        assert!(reader.can_read(2));
+        assert!(reader.can_read(3));
+        assert!(!reader.can_read(4));
+        assert!(!reader.can_read(5));
     }
 
     #[test]
@@ -534,8 +769,8 @@ mod test {
     fn read_quoted_string_no_open() {
         let mut reader = StringReader::new("hello world\"");
         assert_eq!(
-            reader.read_quoted_string(),
-            Err(BrigadierError::ExpectedStartOfQuote)
+            reader.read_quoted_string().unwrap_err().get_type(),
+            &BrigadierError::ExpectedStartOfQuote
         );
         assert_eq!(reader.get_cursor(), 0);
     }
@@ -544,8 +779,8 @@ mod test {
     fn read_quoted_string_no_close() {
         let mut reader = StringReader::new("\"hello world");
         assert_eq!(
-            reader.read_quoted_string(),
-            Err(BrigadierError::ExpectedEndOfQuote)
+            reader.read_quoted_string().unwrap_err().get_type(),
+            &BrigadierError::ExpectedEndOfQuote
         );
         assert_eq!(reader.get_cursor(), 12);
     }
@@ -553,19 +788,35 @@ mod test {
     #[test]
     fn read_quoted_string_invalid_escape() {
         let mut reader = StringReader::new("\"hello\\nworld\"");
+        let error = reader.read_quoted_string().unwrap_err();
+        assert_eq!(error.get_type(), &BrigadierError::InvalidEscape('n'));
+        assert_eq!(error.get_cursor(), Some(7));
+        assert_eq!(reader.get_cursor(), 7);
+    }
+
+    #[test]
+    fn read_quoted_string_invalid_custom_escape() {
+        let syntax = ReaderSyntax::new().with_escape('^');
+        let mut reader = StringReader::new("\"hello^qworld\"").with_syntax(syntax);
+        let error = reader.read_quoted_string().unwrap_err();
         assert_eq!(
-            reader.read_quoted_string(),
-            Err(BrigadierError::InvalidEscape('n'))
+            error.get_type(),
+            &BrigadierError::InvalidEscapeSequence("^q".to_owned())
         );
-        assert_eq!(reader.get_cursor(), 7);
+        assert_eq!(
+            error.get_type().to_string(),
+            "Invalid escape sequence '^q' in quoted string"
+        );
+        assert_eq!(error.get_range(), Some(StringRange::between(6, 8)));
+        assert_eq!(reader.get_cursor(), 6);
     }
 
     #[test]
     fn read_quoted_string_invalid_quote_escape() {
         let mut reader = StringReader::new("'hello\\\"\'world");
         assert_eq!(
-            reader.read_quoted_string(),
-            Err(BrigadierError::InvalidEscape('"'))
+            reader.read_quoted_string().unwrap_err().get_type(),
+            &BrigadierError::InvalidEscape('"')
         );
         assert_eq!(reader.get_cursor(), 7);
     }
@@ -618,14 +869,22 @@ mod test {
     #[test]
     fn read_int_invalid() {
         let mut reader = StringReader::new("12.34");
-        assert_eq!(reader.read_int(), Err(BrigadierError::InvalidInt));
+        let error = reader.read_int().unwrap_err();
+        assert_eq!(
+            error.get_type(),
+            &BrigadierError::InvalidInt("12.34".to_owned())
+        );
+        assert_eq!(error.get_cursor(), Some(0));
         assert_eq!(reader.get_cursor(), 0);
     }
 
     #[test]
     fn read_int_none() {
         let mut reader = StringReader::new("");
-        assert_eq!(reader.read_int(), Err(BrigadierError::ExpectedInt));
+        assert_eq!(
+            reader.read_int().unwrap_err().get_type(),
+            &BrigadierError::ExpectedInt
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
 
@@ -668,14 +927,20 @@ mod test {
     #[test]
     fn read_long_invalid() {
         let mut reader = StringReader::new("12.34");
-        assert_eq!(reader.read_long(), Err(BrigadierError::InvalidLong));
+        assert_eq!(
+            reader.read_long().unwrap_err().get_type(),
+            &BrigadierError::InvalidLong("12.34".to_owned())
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
 
     #[test]
     fn read_long_none() {
         let mut reader = StringReader::new("");
-        assert_eq!(reader.read_long(), Err(BrigadierError::ExpectedLong));
+        assert_eq!(
+            reader.read_long().unwrap_err().get_type(),
+            &BrigadierError::ExpectedLong
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
 
@@ -727,14 +992,20 @@ mod test {
     #[test]
     fn read_double_invalid() {
         let mut reader = StringReader::new("12.34.56");
-        assert_eq!(reader.read_double(), Err(BrigadierError::InvalidDouble));
+        assert_eq!(
+            reader.read_double().unwrap_err().get_type(),
+            &BrigadierError::InvalidDouble("12.34.56".to_owned())
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
 
     #[test]
     fn read_double_none() {
         let mut reader = StringReader::new("");
-        assert_eq!(reader.read_double(), Err(BrigadierError::ExpectedDouble));
+        assert_eq!(
+            reader.read_double().unwrap_err().get_type(),
+            &BrigadierError::ExpectedDouble
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
 
@@ -786,14 +1057,20 @@ mod test {
     #[test]
     fn read_float_invalid() {
         let mut reader = StringReader::new("12.34.56");
-        assert_eq!(reader.read_float(), Err(BrigadierError::InvalidFloat));
+        assert_eq!(
+            reader.read_float().unwrap_err().get_type(),
+            &BrigadierError::InvalidFloat("12.34.56".to_owned())
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
 
     #[test]
     fn read_float_none() {
         let mut reader = StringReader::new("");
-        assert_eq!(reader.read_float(), Err(BrigadierError::ExpectedFloat));
+        assert_eq!(
+            reader.read_float().unwrap_err().get_type(),
+            &BrigadierError::ExpectedFloat
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
 
@@ -825,21 +1102,27 @@ mod test {
     #[test]
     fn expect_incorrect() {
         let mut reader = StringReader::new("bca");
-        assert_eq!(reader.expect('a'), Err(BrigadierError::ExpectedSymbol('a')));
+        assert_eq!(
+            reader.expect('a').unwrap_err().get_type(),
+            &BrigadierError::ExpectedSymbol('a')
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
 
     #[test]
     fn expect_none() {
         let mut reader = StringReader::new("");
-        assert_eq!(reader.expect('a'), Err(BrigadierError::ExpectedSymbol('a')));
+        assert_eq!(
+            reader.expect('a').unwrap_err().get_type(),
+            &BrigadierError::ExpectedSymbol('a')
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
 
     #[test]
     fn read_boolean_correct() {
         let mut reader = StringReader::new("true");
-        assert_eq!(reader.read_boolean().unwrap(), true);
+        assert!(reader.read_boolean().unwrap());
         assert_eq!(reader.get_cursor(), 4);
         assert_eq!(reader.get_read(), "true");
         assert_eq!(reader.get_remaining(), "");
@@ -848,14 +1131,336 @@ mod test {
     #[test]
     fn read_boolean_incorrect() {
         let mut reader = StringReader::new("tuesday");
-        assert_eq!(reader.read_boolean(), Err(BrigadierError::InvalidBool));
+        assert_eq!(
+            reader.read_boolean().unwrap_err().get_type(),
+            &BrigadierError::InvalidBool("tuesday".to_owned())
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
 
     #[test]
     fn read_boolean_none() {
         let mut reader = StringReader::new("");
-        assert_eq!(reader.read_boolean(), Err(BrigadierError::ExpectedBool));
+        assert_eq!(
+            reader.read_boolean().unwrap_err().get_type(),
+            &BrigadierError::ExpectedBool
+        );
+        assert_eq!(reader.get_cursor(), 0);
+    }
+
+    #[test]
+    fn read_multibyte() {
+        let mut reader = StringReader::new("äb😀c");
+        assert_eq!(reader.peek(2).unwrap(), '😀');
+        assert_eq!(reader.read().unwrap(), 'ä');
+        assert_eq!(reader.get_cursor(), 2);
+        assert_eq!(reader.read().unwrap(), 'b');
+        reader.skip();
+        assert_eq!(reader.get_cursor(), 7);
+        assert_eq!(reader.get_read(), "äb😀");
+        assert_eq!(reader.get_remaining(), "c");
+        assert_eq!(reader.read().unwrap(), 'c');
+        assert!(!reader.can_read_char());
+    }
+
+    #[test]
+    fn can_read_multibyte() {
+        let mut reader = StringReader::new("äb");
+        assert!(reader.can_read(2));
+        assert_eq!(reader.peek(1).unwrap(), 'b');
+        assert!(!reader.can_read(3));
+
+        reader.skip();
+        assert!(reader.can_read(1));
+        assert!(!reader.can_read(2));
+        assert!(StringReader::new("ä").can_read(1));
+        assert!(!StringReader::new("ä").can_read(2));
+    }
+
+    #[test]
+    fn skip_whitespace_multibyte() {
+        let mut reader = StringReader::new("\u{3000}ä");
+        reader.skip_whitespace();
+        assert_eq!(reader.get_remaining(), "ä");
+    }
+
+    #[test]
+    fn read_unquoted_string_multibyte() {
+        let mut reader = StringReader::new("Jürgen ok");
+        assert_eq!(reader.read_unqoted_string().unwrap(), "Jürgen");
+        assert_eq!(reader.get_remaining(), " ok");
+    }
+
+    #[test]
+    fn read_quoted_string_multibyte() {
+        let mut reader = StringReader::new("\"héllo 😀\" rest");
+        assert_eq!(reader.read_quoted_string().unwrap(), "héllo 😀");
+        assert_eq!(reader.get_remaining(), " rest");
+    }
+
+    #[test]
+    fn read_quoted_string_invalid_multibyte_escape() {
+        let mut reader = StringReader::new("\"a\\é\"");
+        let error = reader.read_quoted_string().unwrap_err();
+        assert_eq!(error.get_type(), &BrigadierError::InvalidEscape('é'));
+        assert_eq!(reader.get_cursor(), 3);
+        assert_eq!(reader.get_remaining(), "é\"");
+    }
+
+    #[test]
+    fn read_int_rejects_non_ascii_digits() {
+        let mut reader = StringReader::new("٣");
+        assert_eq!(
+            reader.read_int().unwrap_err().get_type(),
+            &BrigadierError::ExpectedInt
+        );
         assert_eq!(reader.get_cursor(), 0);
     }
+
+    #[test]
+    fn owned_input() {
+        let reader = StringReader::from(String::from("Hello!"));
+        let clone = reader.clone();
+        assert_eq!(clone.get_string(), "Hello!");
+        assert!(Arc::ptr_eq(
+            &reader.get_shared_string(),
+            &clone.get_shared_string()
+        ));
+    }
+
+    fn extended(input: &str) -> StringReader {
+        StringReader::new(input).with_extended_numbers(true)
+    }
+
+    #[test]
+    fn read_int_extended() {
+        assert_eq!(extended("0xFF").read_int().unwrap(), 255);
+        assert_eq!(extended("0Xff").read_int().unwrap(), 255);
+        assert_eq!(extended("0b1010").read_int().unwrap(), 10);
+        assert_eq!(extended("1_000_000").read_int().unwrap(), 1_000_000);
+        assert_eq!(extended("+42").read_int().unwrap(), 42);
+        assert_eq!(extended("-0x80000000").read_int().unwrap(), i32::MIN);
+    }
+
+    #[test]
+    fn read_int_extended_stops_at_separator() {
+        let mut reader = extended("0xFF00FF rest");
+        assert_eq!(reader.read_int().unwrap(), 0xFF00FF);
+        assert_eq!(reader.get_remaining(), " rest");
+    }
+
+    #[test]
+    fn read_int_extended_invalid() {
+        for input in ["0x", "0b102", "1__0", "_1", "1_", "0x_FF", "12.5", "1e3"] {
+            let error = extended(input).read_int().unwrap_err();
+            assert_eq!(
+                error.get_type(),
+                &BrigadierError::InvalidInt(input.to_owned()),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn read_int_extended_overflow() {
+        let mut reader = extended("give 0x1_0000_0000 now");
+        reader.set_cursor(5);
+        let error = reader.read_int().unwrap_err();
+        assert_eq!(
+            error.get_type(),
+            &BrigadierError::InvalidInt("0x1_0000_0000".to_owned())
+        );
+        assert_eq!(error.get_cursor(), Some(5));
+        assert_eq!(error.get_range(), Some(StringRange::between(5, 18)));
+        assert_eq!(error.get_context(), Some("give <--[HERE]".to_owned()));
+        assert_eq!(reader.get_cursor(), 5);
+    }
+
+    #[test]
+    fn read_long_extended() {
+        assert_eq!(
+            extended("0xFFFF_FFFF_FFFF").read_long().unwrap(),
+            0xFFFF_FFFF_FFFF
+        );
+        assert_eq!(
+            extended("0x8000000000000000")
+                .read_long()
+                .unwrap_err()
+                .get_type(),
+            &BrigadierError::InvalidLong("0x8000000000000000".to_owned())
+        );
+    }
+
+    #[test]
+    fn read_double_extended() {
+        assert_eq!(extended("1.5e3").read_double().unwrap(), 1500.0);
+        assert_eq!(extended("+2.5E-1").read_double().unwrap(), 0.25);
+        assert_eq!(extended("1_000.5").read_double().unwrap(), 1000.5);
+        assert_eq!(
+            extended("inf").read_double().unwrap_err().get_type(),
+            &BrigadierError::ExpectedDouble
+        );
+        for number in ["1_e5", "1_.5", "1._5", "1e5_"] {
+            assert_eq!(
+                extended(number).read_double().unwrap_err().get_type(),
+                &BrigadierError::InvalidDouble(number.to_owned())
+            );
+        }
+    }
+
+    #[test]
+    fn read_extended_number_with_suffix() {
+        let mut reader = extended("10s");
+        assert_eq!(reader.read_int().unwrap(), 10);
+        assert_eq!(reader.get_remaining(), "s");
+
+        let mut reader = extended("0x1Fg");
+        assert_eq!(reader.read_int().unwrap(), 0x1F);
+        assert_eq!(reader.get_remaining(), "g");
+
+        let mut reader = extended("2.5d");
+        assert_eq!(reader.read_double().unwrap(), 2.5);
+        assert_eq!(reader.get_remaining(), "d");
+
+        let mut reader = extended("1e_5");
+        assert_eq!(reader.read_double().unwrap(), 1.0);
+        assert_eq!(reader.get_remaining(), "e_5");
+
+        let mut reader = extended("3em");
+        assert_eq!(reader.read_float().unwrap(), 3.0);
+        assert_eq!(reader.get_remaining(), "em");
+    }
+
+    #[test]
+    fn read_double_overflow() {
+        let error = extended("1e999").read_double().unwrap_err();
+        assert_eq!(
+            error.get_type(),
+            &BrigadierError::InvalidDouble("1e999".to_owned())
+        );
+        assert_eq!(error.get_cursor(), Some(0));
+    }
+
+    #[test]
+    fn read_float_extended() {
+        assert_eq!(extended("2e2").read_float().unwrap(), 200.0);
+        assert_eq!(
+            extended("1e39").read_float().unwrap_err().get_type(),
+            &BrigadierError::InvalidFloat("1e39".to_owned())
+        );
+    }
+
+    #[test]
+    fn read_int_not_extended_by_default() {
+        let mut reader = StringReader::new("0xFF");
+        assert_eq!(reader.read_int().unwrap(), 0);
+        assert_eq!(reader.get_remaining(), "xFF");
+        assert!(!reader.has_extended_numbers());
+    }
+
+    #[test]
+    fn read_unquoted_string_custom_syntax() {
+        let mut reader = StringReader::new("minecraft:stone/top rest")
+            .with_syntax(ReaderSyntax::new().allow_unquoted([':', '/']));
+        assert_eq!(reader.read_unqoted_string().unwrap(), "minecraft:stone/top");
+        assert_eq!(reader.get_remaining(), " rest");
+    }
+
+    #[test]
+    fn read_quoted_string_custom_syntax() {
+        let syntax = ReaderSyntax::new().with_quotes(['`']).with_escape('^');
+        let mut reader = StringReader::new("`say ^`hi^` ^^` \"no\"").with_syntax(syntax);
+        assert_eq!(reader.read_string().unwrap(), "say `hi` ^");
+        reader.skip();
+        assert_eq!(reader.read_string().unwrap(), "");
+        assert_eq!(reader.get_remaining(), "\"no\"");
+    }
+
+    #[test]
+    fn skip_whitespace_custom_syntax() {
+        let mut reader =
+            StringReader::new(" \tHello!").with_syntax(ReaderSyntax::new().with_whitespace([' ']));
+        reader.skip_whitespace();
+        assert_eq!(reader.get_remaining(), "\tHello!");
+    }
+
+    fn rich(input: &str) -> StringReader {
+        StringReader::new(input).with_syntax(ReaderSyntax::new().with_rich_escapes(true))
+    }
+
+    #[test]
+    fn read_quoted_string_rich_escapes() {
+        let mut reader = rich(r#""a\nb\tc\r\0\x41\u{e9}\u{1F600}\"\\" rest"#);
+        assert_eq!(
+            reader.read_quoted_string().unwrap(),
+            "a\nb\tc\r\0A\u{e9}\u{1F600}\"\\"
+        );
+        assert_eq!(reader.get_remaining(), " rest");
+    }
+
+    #[test]
+    fn read_quoted_string_rich_escapes_off_by_default() {
+        let mut reader = StringReader::new(r#""a\nb""#);
+        let err = reader.read_quoted_string().unwrap_err();
+        assert_eq!(err.get_type(), &BrigadierError::InvalidEscape('n'));
+        assert_eq!(err.get_cursor(), Some(3));
+    }
+
+    #[test]
+    fn read_quoted_string_rich_unknown_escape() {
+        let mut reader = rich(r#""a\qb""#);
+        let err = reader.read_quoted_string().unwrap_err();
+        assert_eq!(err.get_type(), &BrigadierError::InvalidEscape('q'));
+        assert_eq!(err.get_cursor(), Some(3));
+    }
+
+    #[test]
+    fn read_quoted_string_rich_invalid_sequences() {
+        for (input, sequence, range) in [
+            (r#""ab\x4g""#, r"\x4", 3..6),
+            (r#""ab\u{}""#, r"\u{}", 3..7),
+            (r#""ab\u{110000}""#, r"\u{110000}", 3..13),
+            (r#""ab\u{d800}""#, r"\u{d800}", 3..11),
+            (r#""ab\u{1F600""#, r"\u{1F600", 3..11),
+            (r#""ab\u41""#, r"\u41", 3..7),
+        ] {
+            let mut reader = rich(input);
+            let err = reader.read_quoted_string().unwrap_err();
+            assert_eq!(
+                err.get_type(),
+                &BrigadierError::InvalidEscapeSequence(sequence.to_owned()),
+                "{input}"
+            );
+            assert_eq!(
+                err.get_range(),
+                Some(StringRange::between(range.start, range.end)),
+                "{input}"
+            );
+            assert_eq!(reader.get_cursor(), range.start, "{input}");
+        }
+    }
+
+    #[test]
+    fn escape_round_trips() {
+        for syntax in [
+            ReaderSyntax::new(),
+            ReaderSyntax::new().with_rich_escapes(true),
+            ReaderSyntax::new().with_quotes(['`']).with_escape('^'),
+        ] {
+            for input in [
+                "",
+                "hello",
+                "say \"hi\"",
+                r"C:\temp",
+                "line\nbreak\t\u{7}",
+                "ünï ^`'",
+            ] {
+                let escaped = syntax.escape(input).unwrap();
+                let mut reader = StringReader::new(escaped.as_str()).with_syntax(syntax.clone());
+                assert_eq!(reader.read_string().unwrap(), input, "{escaped}");
+                assert!(!reader.can_read_char(), "{escaped}");
+            }
+        }
+    }
 }
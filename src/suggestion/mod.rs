@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod suggestion;
 pub mod suggestion_provider;
 pub mod suggestions;
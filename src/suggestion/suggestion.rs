@@ -44,11 +44,11 @@ impl Suggestion {
 
         let mut result = String::new();
         if self.range.get_start() > 0 {
-            result.push_str(&input[..self.range.get_start()]);
+            result.push_str(&input[..input.floor_char_boundary(self.range.get_start())]);
         }
         result.push_str(&self.text);
         if self.range.get_end() < input.len() {
-            result.push_str(&input[self.range.get_end()..]);
+            result.push_str(&input[input.floor_char_boundary(self.range.get_end())..]);
         }
 
         result
@@ -61,11 +61,17 @@ impl Suggestion {
 
         let mut result = String::new();
         if range.get_start() < self.range.get_start() {
-            result.push_str(&command[range.get_start()..self.range.get_start()]);
+            result.push_str(
+                &StringRange::between(range.get_start(), self.range.get_start())
+                    .get_from_string(command),
+            );
         }
         result.push_str(&self.text);
         if range.get_end() > self.range.get_end() {
-            result.push_str(&command[self.range.get_end()..range.get_end()]);
+            result.push_str(
+                &StringRange::between(self.range.get_end(), range.get_end())
+                    .get_from_string(command),
+            );
         }
 
         Self {
@@ -187,4 +193,26 @@ mod test {
             Suggestion::new(StringRange::between(0, 12), "Hello strangers!".to_owned())
         );
     }
+
+    #[test]
+    fn apply_multibyte() {
+        let suggestion = Suggestion::new(StringRange::between(4, 10), "ö".to_owned());
+        assert_eq!(suggestion.apply("say äöü!"), "say ö!");
+    }
+
+    #[test]
+    fn apply_inside_character() {
+        let suggestion = Suggestion::new(StringRange::between(5, 5), "x".to_owned());
+        assert_eq!(suggestion.apply("say äb"), "say xäb");
+    }
+
+    #[test]
+    fn expand_multibyte() {
+        let suggestion = Suggestion::new(StringRange::between(3, 3), "ß".to_owned());
+
+        assert_eq!(
+            suggestion.expand("ä😀", StringRange::between(0, 6)),
+            Suggestion::new(StringRange::between(0, 6), "äß😀".to_owned())
+        );
+    }
 }
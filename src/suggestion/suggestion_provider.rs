@@ -1,11 +1,91 @@
-use crate::{context::command_context::CommandContext, error::BrigadierError};
+use crate::{
+    context::command_context::CommandContext, error::BrigadierError,
+    exceptions::CommandSyntaxException, future::BoxFuture,
+};
 
 use super::{suggestions::Suggestions, suggestions_builder::SuggestionsBuilder};
 
-pub trait SuggestionProvider<S> {
+pub trait SuggestionProvider<S>: Send + Sync {
     fn get_suggestions(
         &self,
-        context: CommandContext<S>,
+        context: &CommandContext<S>,
         builder: &mut SuggestionsBuilder,
-    ) -> Result<Suggestions, BrigadierError>;
+    ) -> Result<Suggestions, CommandSyntaxException>;
+
+    /// The suggestions to offer when completing asynchronously. Defaults to
+    /// [`get_suggestions`](Self::get_suggestions).
+    fn get_suggestions_async<'a>(
+        &'a self,
+        context: &'a CommandContext<S>,
+        builder: &'a mut SuggestionsBuilder,
+    ) -> BoxFuture<'a, Result<Suggestions, CommandSyntaxException>>
+    where
+        S: Send + Sync,
+    {
+        Box::pin(std::future::ready(self.get_suggestions(context, builder)))
+    }
+}
+
+impl<S, F> SuggestionProvider<S> for F
+where
+    F: Fn(
+            &CommandContext<S>,
+            &mut SuggestionsBuilder,
+        ) -> Result<Suggestions, CommandSyntaxException>
+        + Send
+        + Sync,
+{
+    fn get_suggestions(
+        &self,
+        context: &CommandContext<S>,
+        builder: &mut SuggestionsBuilder,
+    ) -> Result<Suggestions, CommandSyntaxException> {
+        self(context, builder)
+    }
+}
+
+/// A suggestion provider that returns a future, created with [`async_suggestions`]. It can only
+/// be asked for suggestions by
+/// [`CommandDispatcher::get_completion_suggestions_async`](crate::command_dispatcher::CommandDispatcher::get_completion_suggestions_async).
+pub struct AsyncSuggestionProvider<F>(F);
+
+pub fn async_suggestions<S, F>(f: F) -> AsyncSuggestionProvider<F>
+where
+    F: for<'a, 'b> Fn(
+            &'a CommandContext<'b, S>,
+            &'a mut SuggestionsBuilder,
+        ) -> BoxFuture<'a, Result<Suggestions, CommandSyntaxException>>
+        + Send
+        + Sync,
+{
+    AsyncSuggestionProvider(f)
+}
+
+impl<S, F> SuggestionProvider<S> for AsyncSuggestionProvider<F>
+where
+    F: for<'a, 'b> Fn(
+            &'a CommandContext<'b, S>,
+            &'a mut SuggestionsBuilder,
+        ) -> BoxFuture<'a, Result<Suggestions, CommandSyntaxException>>
+        + Send
+        + Sync,
+{
+    fn get_suggestions(
+        &self,
+        _context: &CommandContext<S>,
+        _builder: &mut SuggestionsBuilder,
+    ) -> Result<Suggestions, CommandSyntaxException> {
+        Err(BrigadierError::RequiresAsyncExecution.create())
+    }
+
+    fn get_suggestions_async<'a>(
+        &'a self,
+        context: &'a CommandContext<S>,
+        builder: &'a mut SuggestionsBuilder,
+    ) -> BoxFuture<'a, Result<Suggestions, CommandSyntaxException>>
+    where
+        S: Send + Sync,
+    {
+        (self.0)(context, builder)
+    }
 }
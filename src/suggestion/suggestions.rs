@@ -4,6 +4,7 @@ use crate::context::string_range::StringRange;
 
 use super::suggestion::Suggestion;
 
+#[derive(Debug, PartialEq)]
 pub struct Suggestions {
     pub(crate) suggestions: Vec<Suggestion>,
     pub(crate) range: StringRange,
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, future::Ready};
 
 use crate::context::string_range::StringRange;
 
@@ -17,13 +17,14 @@ pub struct SuggestionsBuilder {
 
 impl SuggestionsBuilder {
     pub fn new(input: &str, start: usize) -> Self {
-        let input_lower_case = input.to_lowercase();
+        // Lower-casing can change byte lengths, so `start` is only meaningful in `input`.
+        let remaining = &input[input.floor_char_boundary(start)..];
         Self {
             input: input.to_owned(),
-            input_lower_case: input_lower_case.to_owned(),
+            input_lower_case: input.to_lowercase(),
             start,
-            remaining: input.to_owned().split_off(start),
-            remaining_lower_case: input_lower_case.to_owned().split_off(start),
+            remaining: remaining.to_owned(),
+            remaining_lower_case: remaining.to_lowercase(),
             result: HashSet::new(),
         }
     }
@@ -48,6 +49,11 @@ impl SuggestionsBuilder {
         Suggestions::create(&self.input, self.result.clone())
     }
 
+    /// [`build`](Self::build) as an already completed future, for async suggestion providers.
+    pub fn build_future(&self) -> Ready<Suggestions> {
+        std::future::ready(self.build())
+    }
+
     pub fn suggest(&mut self, text: &str) -> &mut Self {
         if text == self.remaining {
             return self;
@@ -112,7 +118,7 @@ mod test {
             )]
         );
         assert_eq!(result.get_range(), &StringRange::between(6, 7));
-        assert_eq!(result.is_empty(), false);
+        assert!(!result.is_empty());
     }
 
     #[test]
@@ -127,7 +133,7 @@ mod test {
             )]
         );
         assert_eq!(result.get_range(), &StringRange::between(6, 7));
-        assert_eq!(result.is_empty(), false);
+        assert!(!result.is_empty());
     }
 
     #[test]
@@ -135,7 +141,7 @@ mod test {
         let mut builder = SuggestionsBuilder::new("Hello w", 6);
         let result = builder.suggest("w").build();
         assert_eq!(result.get_list(), &[]);
-        assert_eq!(result.is_empty(), true);
+        assert!(result.is_empty());
     }
 
     #[test]
@@ -157,7 +163,7 @@ mod test {
             ]
         );
         assert_eq!(result.get_range(), &StringRange::between(6, 7));
-        assert_eq!(result.is_empty(), false);
+        assert!(!result.is_empty());
     }
 
     #[test]
@@ -193,4 +199,11 @@ mod test {
 
     // Suggestions result = builder.suggest("2").suggest("4").suggest("6").suggest("8").suggest("30").suggest("32").build();
     // Suggestions result = builder.suggest(2).suggest(4).suggest(6).suggest(8).suggest(30).suggest(32).build();
+
+    #[test]
+    fn new_with_lower_case_changing_length() {
+        let builder = SuggestionsBuilder::new("İ Wörld", 3);
+        assert_eq!(builder.get_remaining(), "Wörld");
+        assert_eq!(builder.get_remaining_lower_case(), "wörld");
+    }
 }
@@ -1,23 +1,264 @@
+use std::{fmt::Display, sync::Arc};
+
 use linked_hash_map::LinkedHashMap;
 
 use crate::{
-    arguments::ArgumentType, command::Command, redirect_modifier::RedirectModifier,
-    suggestion::suggestion_provider::SuggestionProvider,
+    arguments::ErasedArgumentType,
+    command::Command,
+    context::{
+        command_context::CommandContext, command_context_builder::CommandContextBuilder,
+        parsed_argument::ParsedArgument, string_range::StringRange,
+    },
+    error::BrigadierError,
+    exceptions::CommandSyntaxException,
+    future::BoxFuture,
+    reader_syntax::ReaderSyntax,
+    redirect_modifier::RedirectModifier,
+    string_reader::StringReader,
+    suggestion::{
+        suggestion_provider::SuggestionProvider, suggestions::Suggestions,
+        suggestions_builder::SuggestionsBuilder,
+    },
+};
+
+use super::{
+    command_node::{CommandNode, Requirement},
+    node_id::NodeId,
 };
 
-use super::{command_node::CommandNode, literal_command_node::LiteralCommandNode};
+const USAGE_ARGUMENT_OPEN: &str = "<";
+const USAGE_ARGUMENT_CLOSE: &str = ">";
 
-pub struct ArgumentCommandNode<S, T> {
-    children: LinkedHashMap<String, Box<dyn CommandNode<S>>>,
-    literals: LinkedHashMap<String, LiteralCommandNode<S>>,
-    arguments: LinkedHashMap<String, ArgumentCommandNode<S, T>>,
-    requirement: Option<Box<dyn Fn(&S) -> bool>>,
-    redirect: Option<Box<dyn CommandNode<S>>>,
+pub struct ArgumentCommandNode<S> {
+    children: LinkedHashMap<String, NodeId>,
+    requirement: Requirement<S>,
+    redirect: Option<NodeId>,
     modifier: Option<Box<dyn RedirectModifier<S>>>,
     forks: bool,
-    command: Box<Command<S>>,
+    command: Option<Command<S>>,
 
     name: String,
-    argument_type: Box<dyn ArgumentType<S, Type = T>>,
-    custom_suggestions: Box<dyn SuggestionProvider<S>>,
+    argument_type: Box<dyn ErasedArgumentType<S>>,
+    custom_suggestions: Option<Box<dyn SuggestionProvider<S>>>,
+}
+
+impl<S> ArgumentCommandNode<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        argument_type: Box<dyn ErasedArgumentType<S>>,
+        command: Option<Command<S>>,
+        requirement: Requirement<S>,
+        redirect: Option<NodeId>,
+        modifier: Option<Box<dyn RedirectModifier<S>>>,
+        forks: bool,
+        custom_suggestions: Option<Box<dyn SuggestionProvider<S>>>,
+    ) -> Self {
+        Self {
+            children: LinkedHashMap::new(),
+            requirement,
+            redirect,
+            modifier,
+            forks,
+            command,
+            name,
+            argument_type,
+            custom_suggestions,
+        }
+    }
+
+    pub fn get_type(&self) -> &dyn ErasedArgumentType<S> {
+        self.argument_type.as_ref()
+    }
+
+    pub fn get_custom_suggestions(&self) -> Option<&dyn SuggestionProvider<S>> {
+        self.custom_suggestions.as_deref()
+    }
+}
+
+impl<S> CommandNode<S> for ArgumentCommandNode<S> {
+    fn can_use(&self, source: &S) -> bool {
+        (self.requirement)(source)
+    }
+
+    fn is_valid_input(&self, input: &str, syntax: &Arc<ReaderSyntax>) -> bool {
+        let mut reader = StringReader::new(input).with_syntax(syntax.clone());
+        self.argument_type.parse_erased(&mut reader).is_ok()
+            && (!reader.can_read_char() || reader.peek_char() == Ok(' '))
+    }
+
+    fn get_sorted_key(&self) -> &str {
+        &self.name
+    }
+
+    fn is_fork(&self) -> bool {
+        self.forks
+    }
+
+    fn get_examples(&self) -> Vec<String> {
+        self.argument_type.get_examples()
+    }
+
+    fn list_suggestions(
+        &self,
+        context: &CommandContext<S>,
+        builder: &mut SuggestionsBuilder,
+    ) -> Result<Suggestions, CommandSyntaxException> {
+        match &self.custom_suggestions {
+            Some(provider) => or_no_suggestions(provider.get_suggestions(context, builder)),
+            None => Ok(self.argument_type.list_suggestions(context, builder)),
+        }
+    }
+
+    fn list_suggestions_async<'a>(
+        &'a self,
+        context: &'a CommandContext<S>,
+        builder: &'a mut SuggestionsBuilder,
+    ) -> BoxFuture<'a, Result<Suggestions, CommandSyntaxException>>
+    where
+        S: Send + Sync,
+    {
+        match &self.custom_suggestions {
+            Some(provider) => Box::pin(async move {
+                or_no_suggestions(provider.get_suggestions_async(context, builder).await)
+            }),
+            None => Box::pin(std::future::ready(Ok(self
+                .argument_type
+                .list_suggestions(context, builder)))),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_usage_text(&self) -> String {
+        USAGE_ARGUMENT_OPEN.to_owned() + &self.name + USAGE_ARGUMENT_CLOSE
+    }
+
+    fn get_command(&self) -> Option<&Command<S>> {
+        self.command.as_ref()
+    }
+
+    fn set_command(&mut self, command: Command<S>) {
+        self.command = Some(command);
+    }
+
+    fn get_children(&self) -> Vec<NodeId> {
+        self.children.values().copied().collect()
+    }
+
+    fn get_child(&self, name: &str) -> Option<NodeId> {
+        self.children.get(name).copied()
+    }
+
+    fn add_child(&mut self, name: String, child: NodeId) {
+        self.children.insert(name, child);
+    }
+
+    fn get_redirect(&self) -> Option<NodeId> {
+        self.redirect
+    }
+
+    fn get_redirect_modifier(&self) -> Option<&dyn RedirectModifier<S>> {
+        self.modifier.as_deref()
+    }
+
+    fn parse(
+        &self,
+        reader: &mut StringReader,
+        context_builder: &mut CommandContextBuilder<S>,
+    ) -> Result<StringRange, CommandSyntaxException> {
+        let start = reader.get_cursor();
+        let result = self.argument_type.parse_erased(reader)?;
+        let parsed = ParsedArgument::new(start, reader.get_cursor(), Arc::from(result));
+        let range = parsed.get_range();
+        context_builder.with_argument(&self.name, parsed);
+        Ok(range)
+    }
+}
+
+impl<S> Display for ArgumentCommandNode<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<argument {}:{}>", self.name, self.argument_type)
+    }
+}
+
+/// A provider that fails simply has nothing to suggest, unless it failed because it can only
+/// run asynchronously, which is a mistake in how the dispatcher was called.
+fn or_no_suggestions(
+    result: Result<Suggestions, CommandSyntaxException>,
+) -> Result<Suggestions, CommandSyntaxException> {
+    match result {
+        Err(err) if err.get_type() != &BrigadierError::RequiresAsyncExecution => {
+            Ok(Suggestions::empty())
+        }
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{arguments::IntegerArgumentType, command_dispatcher::CommandDispatcher};
+
+    use super::*;
+
+    fn node() -> ArgumentCommandNode<()> {
+        ArgumentCommandNode::new(
+            "foo".to_owned(),
+            Box::new(IntegerArgumentType::new()),
+            None,
+            Box::new(|_| true),
+            None,
+            None,
+            false,
+            None,
+        )
+    }
+
+    #[test]
+    fn parse() {
+        let dispatcher = CommandDispatcher::new();
+        let node = node();
+        let mut reader = StringReader::new("123 456");
+        let mut context_builder =
+            CommandContextBuilder::new(&dispatcher, (), dispatcher.get_root(), 0);
+        let range = node.parse(&mut reader, &mut context_builder).unwrap();
+        assert_eq!(reader.get_remaining(), " 456");
+        assert_eq!(range, StringRange::between(0, 3));
+
+        let argument = &context_builder.get_arguments()["foo"];
+        assert_eq!(argument.get_range(), StringRange::between(0, 3));
+        assert_eq!(argument.get_result().downcast_ref::<i32>(), Some(&123));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let dispatcher = CommandDispatcher::new();
+        let node = node();
+        let mut reader = StringReader::new("foo");
+        let mut context_builder =
+            CommandContextBuilder::new(&dispatcher, (), dispatcher.get_root(), 0);
+        assert!(node.parse(&mut reader, &mut context_builder).is_err());
+    }
+
+    #[test]
+    fn usage() {
+        assert_eq!(node().get_usage_text(), "<foo>");
+    }
+
+    #[test]
+    fn is_valid_input() {
+        let node = node();
+        let syntax = Arc::default();
+        assert!(node.is_valid_input("123", &syntax));
+        assert!(node.is_valid_input("123 foo", &syntax));
+        assert!(!node.is_valid_input("123foo", &syntax));
+        assert!(!node.is_valid_input("foo", &syntax));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(node().to_string(), "<argument foo:integer>");
+    }
 }
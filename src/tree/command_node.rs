@@ -1,42 +1,71 @@
+use std::sync::Arc;
+
 use crate::{
-    ambiguity_consumer::AmbiguityConsumer,
     // builder::argument_builder::ArgumentBuilder,
     command::Command,
-    context::command_context::CommandContext,
+    context::{
+        command_context::CommandContext, command_context_builder::CommandContextBuilder,
+        string_range::StringRange,
+    },
+    exceptions::CommandSyntaxException,
+    future::BoxFuture,
+    reader_syntax::ReaderSyntax,
     redirect_modifier::RedirectModifier,
     string_reader::StringReader,
     suggestion::{suggestions::Suggestions, suggestions_builder::SuggestionsBuilder},
 };
 
-pub trait CommandNode<S> {
-    fn can_use(&self, source: &S) -> bool;
+use super::{literal_command_node::LiteralCommandNode, node_id::NodeId};
 
-    fn find_ambiguities(&self, finder: &mut Box<dyn AmbiguityConsumer<S>>);
+pub type Requirement<S> = Box<dyn Fn(&S) -> bool + Send + Sync>;
+
+pub trait CommandNode<S>: Send + Sync {
+    fn can_use(&self, source: &S) -> bool;
 
-    fn is_valid_input(&self, input: &str) -> bool;
+    /// Whether this node would parse `input` when it is read with `syntax`.
+    fn is_valid_input(&self, input: &str, syntax: &Arc<ReaderSyntax>) -> bool;
 
     fn get_sorted_key(&self) -> &str;
-    fn get_relevant_nodes(&self, input: &str) -> Vec<Box<dyn CommandNode<S>>>;
     fn is_fork(&self) -> bool;
     fn get_examples(&self) -> Vec<String>;
     fn list_suggestions(
         &self,
-        context: &mut CommandContext<S>,
+        context: &CommandContext<S>,
         builder: &mut SuggestionsBuilder,
-    ) -> Suggestions;
+    ) -> Result<Suggestions, CommandSyntaxException>;
+
+    /// The suggestions to offer when completing asynchronously. Defaults to
+    /// [`list_suggestions`](Self::list_suggestions).
+    fn list_suggestions_async<'a>(
+        &'a self,
+        context: &'a CommandContext<S>,
+        builder: &'a mut SuggestionsBuilder,
+    ) -> BoxFuture<'a, Result<Suggestions, CommandSyntaxException>>
+    where
+        S: Send + Sync,
+    {
+        Box::pin(std::future::ready(self.list_suggestions(context, builder)))
+    }
 
     fn get_name(&self) -> &str;
-    fn get_usage(&self) -> &str;
-    fn get_command(&self) -> Option<Box<Command<S>>>;
-    fn get_children(&self) -> Vec<Box<dyn CommandNode<S>>>;
-    fn get_child(&self, name: &str) -> Option<Box<dyn CommandNode<S>>>;
-    fn add_child(&mut self, child: Box<dyn CommandNode<S>>);
+    fn get_usage_text(&self) -> String;
+    fn get_command(&self) -> Option<&Command<S>>;
+    fn set_command(&mut self, command: Command<S>);
+    fn get_children(&self) -> Vec<NodeId>;
+    fn get_child(&self, name: &str) -> Option<NodeId>;
+    fn add_child(&mut self, name: String, child: NodeId);
     // fn create_builder(&self) -> Box<dyn ArgumentBuilder<S>>;
-    fn get_redirect(&self) -> Option<Box<dyn CommandNode<S>>>;
-    fn get_redirect_modifier(&self) -> Option<Box<dyn RedirectModifier<S>>>;
+    fn get_redirect(&self) -> Option<NodeId>;
+    fn get_redirect_modifier(&self) -> Option<&dyn RedirectModifier<S>>;
+
+    /// Reads this node's input from `reader`, returning the range it covered.
     fn parse(
         &self,
         reader: &mut StringReader,
-        context: &mut CommandContext<S>,
-    ) -> Result<(), String>;
+        context_builder: &mut CommandContextBuilder<S>,
+    ) -> Result<StringRange, CommandSyntaxException>;
+
+    fn as_literal(&self) -> Option<&LiteralCommandNode<S>> {
+        None
+    }
 }
@@ -1,114 +1,261 @@
+use std::{fmt::Display, sync::Arc};
+
 use linked_hash_map::LinkedHashMap;
 
-use crate::{command::Command, redirect_modifier::RedirectModifier};
+use crate::{
+    command::Command,
+    context::{
+        command_context::CommandContext, command_context_builder::CommandContextBuilder,
+        string_range::StringRange,
+    },
+    error::BrigadierError,
+    exceptions::CommandSyntaxException,
+    reader_syntax::ReaderSyntax,
+    redirect_modifier::RedirectModifier,
+    string_reader::StringReader,
+    suggestion::{suggestions::Suggestions, suggestions_builder::SuggestionsBuilder},
+};
 
-use super::command_node::CommandNode;
+use super::{
+    command_node::{CommandNode, Requirement},
+    node_id::NodeId,
+};
 
 pub struct LiteralCommandNode<S> {
+    children: LinkedHashMap<String, NodeId>,
+    requirement: Requirement<S>,
+    redirect: Option<NodeId>,
+    modifier: Option<Box<dyn RedirectModifier<S>>>,
+    forks: bool,
+    command: Option<Command<S>>,
+
     literal: String,
     literal_lower_case: String,
-    _marker: std::marker::PhantomData<S>,
 }
 
 impl<S> LiteralCommandNode<S> {
     pub fn new(
         literal: String,
-        command: Option<Box<Command<S>>>,
-        requirement: Box<dyn Fn(&S) -> bool>,
+        command: Option<Command<S>>,
+        requirement: Requirement<S>,
+        redirect: Option<NodeId>,
+        modifier: Option<Box<dyn RedirectModifier<S>>>,
+        forks: bool,
     ) -> Self {
         Self {
-            literal: literal.clone(),
-            literal_lower_case: literal.clone().to_lowercase(),
-            _marker: std::marker::PhantomData,
+            children: LinkedHashMap::new(),
+            requirement,
+            redirect,
+            modifier,
+            forks,
+            command,
+            literal_lower_case: literal.to_lowercase(),
+            literal,
         }
     }
-}
 
-impl<S> CommandNode<S> for LiteralCommandNode<S> {
-    fn can_use(&self, source: &S) -> bool {
-        todo!()
+    pub fn get_literal(&self) -> &str {
+        &self.literal
     }
 
-    fn find_ambiguities(
-        &self,
-        finder: &mut Box<dyn crate::ambiguity_consumer::AmbiguityConsumer<S>>,
-    ) {
-        todo!()
+    /// Consumes the literal if it is the next full word of `reader`, returning the end cursor.
+    fn parse_literal(&self, reader: &mut StringReader) -> Option<usize> {
+        if !reader.get_remaining().starts_with(&self.literal) {
+            return None;
+        }
+
+        let start = reader.get_cursor();
+        let end = start + self.literal.len();
+        reader.set_cursor(end);
+        if !reader.can_read_char() || reader.peek_char() == Ok(' ') {
+            Some(end)
+        } else {
+            reader.set_cursor(start);
+            None
+        }
     }
+}
 
-    fn is_valid_input(&self, input: &str) -> bool {
-        todo!()
+impl<S> CommandNode<S> for LiteralCommandNode<S> {
+    fn can_use(&self, source: &S) -> bool {
+        (self.requirement)(source)
     }
 
-    fn get_sorted_key(&self) -> &str {
-        todo!()
+    fn is_valid_input(&self, input: &str, syntax: &Arc<ReaderSyntax>) -> bool {
+        let mut reader = StringReader::new(input).with_syntax(syntax.clone());
+        self.parse_literal(&mut reader).is_some()
     }
 
-    fn get_relevant_nodes(&self, input: &str) -> Vec<Box<dyn CommandNode<S>>> {
-        todo!()
+    fn get_sorted_key(&self) -> &str {
+        &self.literal
     }
 
     fn is_fork(&self) -> bool {
-        todo!()
+        self.forks
     }
 
     fn get_examples(&self) -> Vec<String> {
-        todo!()
+        vec![self.literal.clone()]
     }
 
     fn list_suggestions(
         &self,
-        context: &mut crate::context::command_context::CommandContext<S>,
-        builder: &mut crate::suggestion::suggestions_builder::SuggestionsBuilder,
-    ) -> crate::suggestion::suggestions::Suggestions {
-        todo!()
+        _context: &CommandContext<S>,
+        builder: &mut SuggestionsBuilder,
+    ) -> Result<Suggestions, CommandSyntaxException> {
+        Ok(
+            if self
+                .literal_lower_case
+                .starts_with(builder.get_remaining_lower_case())
+            {
+                builder.suggest(&self.literal).build()
+            } else {
+                Suggestions::empty()
+            },
+        )
     }
 
     fn get_name(&self) -> &str {
-        todo!()
+        &self.literal
     }
 
-    fn get_usage(&self) -> &str {
-        todo!()
+    fn get_usage_text(&self) -> String {
+        self.literal.clone()
     }
 
-    fn get_command(&self) -> Option<Box<Command<S>>> {
-        todo!()
+    fn get_command(&self) -> Option<&Command<S>> {
+        self.command.as_ref()
     }
 
-    fn get_children(&self) -> Vec<Box<dyn CommandNode<S>>> {
-        todo!()
+    fn set_command(&mut self, command: Command<S>) {
+        self.command = Some(command);
     }
 
-    fn get_child(&self, name: &str) -> Option<Box<dyn CommandNode<S>>> {
-        todo!()
+    fn get_children(&self) -> Vec<NodeId> {
+        self.children.values().copied().collect()
     }
 
-    fn add_child(&mut self, child: Box<dyn CommandNode<S>>) {
-        todo!()
+    fn get_child(&self, name: &str) -> Option<NodeId> {
+        self.children.get(name).copied()
     }
 
-    fn get_redirect(&self) -> Option<Box<dyn CommandNode<S>>> {
-        todo!()
+    fn add_child(&mut self, name: String, child: NodeId) {
+        self.children.insert(name, child);
     }
 
-    fn get_redirect_modifier(&self) -> Option<Box<dyn RedirectModifier<S>>> {
-        todo!()
+    fn get_redirect(&self) -> Option<NodeId> {
+        self.redirect
+    }
+
+    fn get_redirect_modifier(&self) -> Option<&dyn RedirectModifier<S>> {
+        self.modifier.as_deref()
     }
 
     fn parse(
         &self,
-        reader: &mut crate::string_reader::StringReader,
-        context: &mut crate::context::command_context::CommandContext<S>,
-    ) -> Result<(), String> {
-        todo!()
+        reader: &mut StringReader,
+        _context_builder: &mut CommandContextBuilder<S>,
+    ) -> Result<StringRange, CommandSyntaxException> {
+        let start = reader.get_cursor();
+        match self.parse_literal(reader) {
+            Some(end) => Ok(StringRange::between(start, end)),
+            None => {
+                Err(BrigadierError::LiteralIncorrect(self.literal.clone())
+                    .create_with_context(reader))
+            }
+        }
+    }
+
+    fn as_literal(&self) -> Option<&LiteralCommandNode<S>> {
+        Some(self)
+    }
+}
+
+impl<S> Display for LiteralCommandNode<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<literal {}>", self.literal)
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::command_dispatcher::CommandDispatcher;
+
+    use super::*;
+
+    fn node() -> LiteralCommandNode<()> {
+        LiteralCommandNode::new(
+            "foo".to_owned(),
+            None,
+            Box::new(|_| true),
+            None,
+            None,
+            false,
+        )
+    }
+
     #[test]
     fn parse() {
-        // let context_builder = CommandContextBuilder::new();
+        let dispatcher = CommandDispatcher::new();
+        let node = node();
+        let mut reader = StringReader::new("foo bar");
+        let mut context_builder =
+            CommandContextBuilder::new(&dispatcher, (), dispatcher.get_root(), 0);
+        let range = node.parse(&mut reader, &mut context_builder).unwrap();
+        assert_eq!(reader.get_remaining(), " bar");
+        assert_eq!(range, StringRange::between(0, 3));
+    }
+
+    #[test]
+    fn parse_exact() {
+        let dispatcher = CommandDispatcher::new();
+        let node = node();
+        let mut reader = StringReader::new("foo");
+        let mut context_builder =
+            CommandContextBuilder::new(&dispatcher, (), dispatcher.get_root(), 0);
+        node.parse(&mut reader, &mut context_builder).unwrap();
+        assert_eq!(reader.get_remaining(), "");
+    }
+
+    #[test]
+    fn parse_similar() {
+        let dispatcher = CommandDispatcher::new();
+        let node = node();
+        let mut reader = StringReader::new("foobar");
+        let mut context_builder =
+            CommandContextBuilder::new(&dispatcher, (), dispatcher.get_root(), 0);
+        assert_eq!(
+            node.parse(&mut reader, &mut context_builder)
+                .unwrap_err()
+                .get_type(),
+            &BrigadierError::LiteralIncorrect("foo".to_owned())
+        );
+        assert_eq!(reader.get_cursor(), 0);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let dispatcher = CommandDispatcher::new();
+        let node = node();
+        let mut reader = StringReader::new("bar");
+        let mut context_builder =
+            CommandContextBuilder::new(&dispatcher, (), dispatcher.get_root(), 0);
+        assert_eq!(
+            node.parse(&mut reader, &mut context_builder)
+                .unwrap_err()
+                .get_type(),
+            &BrigadierError::LiteralIncorrect("foo".to_owned())
+        );
+        assert_eq!(reader.get_cursor(), 0);
+    }
+
+    #[test]
+    fn is_valid_input() {
+        let node = node();
+        let syntax = Arc::default();
+        assert!(node.is_valid_input("foo", &syntax));
+        assert!(node.is_valid_input("foo bar", &syntax));
+        assert!(!node.is_valid_input("foobar", &syntax));
+        assert!(!node.is_valid_input("fo", &syntax));
     }
 }
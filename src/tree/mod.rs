@@ -1,4 +1,5 @@
 pub mod argument_command_node;
 pub mod command_node;
 pub mod literal_command_node;
+pub mod node_id;
 pub mod root_command_node;
@@ -0,0 +1,4 @@
+/// A handle to a node in a [`CommandDispatcher`](crate::command_dispatcher::CommandDispatcher)'s
+/// tree. Handles are only meaningful for the dispatcher that created them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(pub(crate) usize);
@@ -1,99 +1,113 @@
-use std::fmt::Display;
+use std::{fmt::Display, marker::PhantomData, sync::Arc};
 
-use super::command_node::CommandNode;
+use linked_hash_map::LinkedHashMap;
+
+use crate::{
+    command::Command,
+    context::{
+        command_context::CommandContext, command_context_builder::CommandContextBuilder,
+        string_range::StringRange,
+    },
+    exceptions::CommandSyntaxException,
+    reader_syntax::ReaderSyntax,
+    redirect_modifier::RedirectModifier,
+    string_reader::StringReader,
+    suggestion::{suggestions::Suggestions, suggestions_builder::SuggestionsBuilder},
+};
+
+use super::{command_node::CommandNode, node_id::NodeId};
 
 pub struct RootCommandNode<S> {
-    _marker: std::marker::PhantomData<S>,
+    children: LinkedHashMap<String, NodeId>,
+    _source: PhantomData<fn() -> S>,
 }
 
 impl<S> RootCommandNode<S> {
     pub fn new() -> Self {
         Self {
-            _marker: std::marker::PhantomData,
+            children: LinkedHashMap::new(),
+            _source: PhantomData,
         }
     }
 }
 
-impl<S> CommandNode<S> for RootCommandNode<S> {
-    fn can_use(&self, source: &S) -> bool {
-        todo!()
+impl<S> Default for RootCommandNode<S> {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    fn find_ambiguities(
-        &self,
-        finder: &mut Box<dyn crate::ambiguity_consumer::AmbiguityConsumer<S>>,
-    ) {
-        todo!()
+impl<S> CommandNode<S> for RootCommandNode<S> {
+    fn can_use(&self, _source: &S) -> bool {
+        true
     }
 
-    fn is_valid_input(&self, input: &str) -> bool {
-        todo!()
+    fn is_valid_input(&self, _input: &str, _syntax: &Arc<ReaderSyntax>) -> bool {
+        false
     }
 
     fn get_sorted_key(&self) -> &str {
-        todo!()
-    }
-
-    fn get_relevant_nodes(&self, input: &str) -> Vec<Box<dyn CommandNode<S>>> {
-        todo!()
+        ""
     }
 
     fn is_fork(&self) -> bool {
-        todo!()
+        false
     }
 
     fn get_examples(&self) -> Vec<String> {
-        todo!()
+        Vec::new()
     }
 
     fn list_suggestions(
         &self,
-        context: &mut crate::context::command_context::CommandContext<S>,
-        builder: &mut crate::suggestion::suggestions_builder::SuggestionsBuilder,
-    ) -> crate::suggestion::suggestions::Suggestions {
-        todo!()
+        _context: &CommandContext<S>,
+        _builder: &mut SuggestionsBuilder,
+    ) -> Result<Suggestions, CommandSyntaxException> {
+        Ok(Suggestions::empty())
     }
 
     fn get_name(&self) -> &str {
-        todo!()
+        ""
     }
 
-    fn get_usage(&self) -> &str {
-        todo!()
+    fn get_usage_text(&self) -> String {
+        String::new()
     }
 
-    fn get_command(&self) -> Option<Box<crate::command::Command<S>>> {
-        todo!()
+    fn get_command(&self) -> Option<&Command<S>> {
+        None
     }
 
-    fn get_children(&self) -> Vec<Box<dyn CommandNode<S>>> {
-        todo!()
+    fn set_command(&mut self, _command: Command<S>) {
+        panic!("Cannot set the command of a root node");
     }
 
-    fn get_child(&self, name: &str) -> Option<Box<dyn CommandNode<S>>> {
-        todo!()
+    fn get_children(&self) -> Vec<NodeId> {
+        self.children.values().copied().collect()
     }
 
-    fn add_child(&mut self, child: Box<dyn CommandNode<S>>) {
-        todo!()
+    fn get_child(&self, name: &str) -> Option<NodeId> {
+        self.children.get(name).copied()
     }
 
-    fn get_redirect(&self) -> Option<Box<dyn CommandNode<S>>> {
-        todo!()
+    fn add_child(&mut self, name: String, child: NodeId) {
+        self.children.insert(name, child);
     }
 
-    fn get_redirect_modifier(
-        &self,
-    ) -> Option<Box<dyn crate::redirect_modifier::RedirectModifier<S>>> {
-        todo!()
+    fn get_redirect(&self) -> Option<NodeId> {
+        None
+    }
+
+    fn get_redirect_modifier(&self) -> Option<&dyn RedirectModifier<S>> {
+        None
     }
 
     fn parse(
         &self,
-        reader: &mut crate::string_reader::StringReader,
-        context: &mut crate::context::command_context::CommandContext<S>,
-    ) -> Result<(), String> {
-        todo!()
+        reader: &mut StringReader,
+        _context_builder: &mut CommandContextBuilder<S>,
+    ) -> Result<StringRange, CommandSyntaxException> {
+        Ok(StringRange::at(reader.get_cursor()))
     }
 }
 